@@ -0,0 +1,37 @@
+//!
+//! Reproducible inputs for the benchmarks.
+//!
+
+#![allow(dead_code)]
+
+/// Number of inputs per benchmark.
+pub const SAMPLES: usize = 1000;
+
+/// SplitMix64 generator with a fixed seed, so every run and every machine benchmarks the same inputs.
+pub struct Generator(u64);
+
+impl Default for Generator {
+  fn default() -> Self {
+    Self(0x5eed_1e75_be4a_710a)
+  }
+}
+
+impl Generator {
+  fn next_u64(&mut self) -> u64 {
+    self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = self.0;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+  }
+
+  /// Uniform sample from  [a, b).
+  pub fn uniform(&mut self, a: f64, b: f64) -> f64 {
+    a + (b - a) * (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+  }
+
+  /// `SAMPLES` pairs drawn uniformly from  [a₀, b₀) × [a₁, b₁).
+  pub fn pairs(&mut self, (a0, b0): (f64, f64), (a1, b1): (f64, f64)) -> Vec<(f64, f64)> {
+    (0..SAMPLES).map(|_| (self.uniform(a0, b0), self.uniform(a1, b1))).collect()
+  }
+}
@@ -0,0 +1,56 @@
+//!
+//! Throughput of the Householder iteration of the implied volatility solver.
+//!
+//! This is a regression benchmark, compare against a saved baseline with `cargo bench --bench householder -- --baseline`.
+//!
+//! Replacing the `lazy_static` thresholds by constants did not improve it measurably. With both versions linked into one binary
+//! and three interleaved runs each, the 1681 solves took, in µs:
+//!
+//! ```text
+//!     iterations   lazy_static        const
+//!     0            342  378  399      340  357  434
+//!     1            700  703  664      550  647  680
+//!     2            805  851  879      847  856  771
+//!     3            907  895  808      951  881  804
+//! ```
+//!
+//! Once initialised, a `lazy_static` deref is a load and a predicted branch next to the exp() and erfc() calls of each evaluation.
+//!
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use impl_vol::*;
+use std::hint::black_box;
+
+/// Normalised prices  (β, x, q)  on a grid of log-moneyness and total volatility covering all four segments of the initial guess.
+fn grid() -> Vec<(f64, f64, f64)> {
+  let mut grid = vec![];
+  for i in 0..=40 {
+    let x = -4.0 + 0.2 * i as f64;
+    for j in 1..=40 {
+      let s = 0.05 * j as f64;
+      let q = if x > 0.0 { -1.0 } else { 1.0 };
+      grid.push((normalised_black(x, s, q), x, q));
+    }
+  }
+  grid
+}
+
+fn bench_householder_iterations(c: &mut Criterion) {
+  let grid = grid();
+  let mut group = c.benchmark_group("householder_iterations");
+  group.throughput(Throughput::Elements(grid.len() as u64));
+  for n in 0..=3 {
+    group.bench_with_input(BenchmarkId::from_parameter(n), &n, |b, &n| {
+      b.iter(|| {
+        grid
+          .iter()
+          .map(|&(beta, x, q)| normalised_implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(black_box(beta), black_box(x), black_box(q), n))
+          .sum::<f64>()
+      })
+    });
+  }
+  group.finish();
+}
+
+criterion_group!(benches, bench_householder_iterations);
+criterion_main!(benches);
@@ -0,0 +1,87 @@
+//!
+//! Throughput of the implied volatility solver for typical market inputs.
+//!
+
+mod common;
+
+use common::*;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use impl_vol::*;
+use std::hint::black_box;
+
+/// Undiscounted out-of-the-money prices  (price, f, k, t, q)  with the forward fixed at 100.
+fn options(generator: &mut Generator, moneyness: (f64, f64), volatility: (f64, f64), expiry: (f64, f64)) -> Vec<(f64, f64, f64, f64, f64)> {
+  (0..SAMPLES)
+    .map(|_| {
+      let f = 100.0;
+      let k = f * generator.uniform(moneyness.0, moneyness.1);
+      let sigma = generator.uniform(volatility.0, volatility.1);
+      let t = generator.uniform(expiry.0, expiry.1);
+      let q = if k < f { -1.0 } else { 1.0 };
+      (black(f, k, sigma, t, q), f, k, t, q)
+    })
+    .collect()
+}
+
+fn bench_implied_volatility(c: &mut Criterion) {
+  let mut generator = Generator::default();
+  let cases = [
+    ("atm", options(&mut generator, (0.98, 1.02), (0.1, 0.5), (0.25, 2.0))),
+    ("deep_otm", options(&mut generator, (2.0, 4.0), (0.2, 0.6), (0.25, 2.0))),
+    ("short_dated", options(&mut generator, (0.9, 1.1), (0.1, 0.5), (1.0 / 365.0, 7.0 / 365.0))),
+    ("long_dated", options(&mut generator, (0.5, 2.0), (0.1, 0.5), (10.0, 30.0))),
+  ];
+  let mut group = c.benchmark_group("implied_volatility");
+  for (name, inputs) in cases {
+    group.throughput(Throughput::Elements(inputs.len() as u64));
+    group.bench_function(name, |b| {
+      b.iter(|| {
+        inputs
+          .iter()
+          .map(|&(price, f, k, t, q)| implied_volatility_from_a_transformed_rational_guess(black_box(price), black_box(f), black_box(k), black_box(t), black_box(q)))
+          .sum::<f64>()
+      })
+    });
+  }
+  group.finish();
+}
+
+/// Solves from the previous volatility, a few basis points off as between two ticks, against the cold solves of the same prices.
+fn bench_warm_start(c: &mut Criterion) {
+  let mut generator = Generator::default();
+  let cases = [
+    ("atm", options(&mut generator, (0.98, 1.02), (0.1, 0.5), (0.25, 2.0))),
+    ("deep_otm", options(&mut generator, (2.0, 4.0), (0.2, 0.6), (0.25, 2.0))),
+  ];
+  let mut group = c.benchmark_group("warm_start");
+  for (name, inputs) in cases {
+    let priors: Vec<f64> = inputs
+      .iter()
+      .map(|&(price, f, k, t, q)| implied_volatility_from_a_transformed_rational_guess(price, f, k, t, q) + generator.uniform(-5e-4, 5e-4))
+      .collect();
+    group.throughput(Throughput::Elements(inputs.len() as u64));
+    group.bench_function(format!("{name}/cold"), |b| {
+      b.iter(|| {
+        inputs
+          .iter()
+          .map(|&(price, f, k, t, q)| implied_volatility_from_a_transformed_rational_guess(black_box(price), black_box(f), black_box(k), black_box(t), black_box(q)))
+          .sum::<f64>()
+      })
+    });
+    group.bench_function(format!("{name}/warm"), |b| {
+      b.iter(|| {
+        inputs
+          .iter()
+          .zip(&priors)
+          .map(|(&(price, f, k, t, q), &prior)| {
+            implied_volatility_from_a_transformed_rational_guess_with_prior(black_box(price), black_box(f), black_box(k), black_box(t), black_box(q), black_box(prior))
+          })
+          .sum::<f64>()
+      })
+    });
+  }
+  group.finish();
+}
+
+criterion_group!(benches, bench_implied_volatility, bench_warm_start);
+criterion_main!(benches);
@@ -0,0 +1,58 @@
+//!
+//! Throughput of `normalised_black_call` in each of its evaluation branches and of Cody's error functions.
+//!
+
+mod common;
+
+use common::*;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use impl_vol::*;
+use std::hint::black_box;
+
+/// Inputs  (x, s)  selecting each branch of `normalised_black_call`, with  h = x/s  and  t = s/2.
+fn branches() -> [(&'static str, Vec<(f64, f64)>); 4] {
+  let mut generator = Generator::default();
+  [
+    // h+t < -10+τ: asymptotic expansion.
+    ("asymptotic", generator.pairs((-30.0, -12.0), (0.2, 1.0))),
+    // t < τ and |h| small: small-t expansion.
+    ("small_t", generator.pairs((-0.1, 0.0), (0.05, 0.4))),
+    // Both arguments of Cody's functions above 0.46875: scaled complementary error function.
+    ("erfcx", generator.pairs((-4.0, -2.0), (0.5, 1.0))),
+    // Near the money with large total volatility, h+t > 0.85: norm_cdf where regions 3 and 4 are not optimised for Cody's
+    // functions, else the complementary error function of h+t and the scaled one of h-t.
+    (
+      if cfg!(feature = "DO_NOT_OPTIMISE_NORMALISED_BLACK_IN_REGIONS_3_AND_4_FOR_CODYS_FUNCTIONS") {
+        "norm_cdf"
+      } else {
+        "erfc_erfcx"
+      },
+      generator.pairs((-0.5, 0.0), (2.5, 3.5)),
+    ),
+  ]
+}
+
+fn bench_normalised_black_call(c: &mut Criterion) {
+  let mut group = c.benchmark_group("normalised_black_call");
+  for (name, inputs) in branches() {
+    group.throughput(Throughput::Elements(inputs.len() as u64));
+    group.bench_function(name, |b| {
+      b.iter(|| inputs.iter().map(|&(x, s)| normalised_black_call(black_box(x), black_box(s))).sum::<f64>())
+    });
+  }
+  group.finish();
+}
+
+fn bench_erf_cody(c: &mut Criterion) {
+  let mut generator = Generator::default();
+  let inputs: Vec<f64> = (0..SAMPLES).map(|_| generator.uniform(-6.0, 6.0)).collect();
+  let mut group = c.benchmark_group("erf_cody");
+  group.throughput(Throughput::Elements(inputs.len() as u64));
+  for (name, function) in [("erf", erf_cody as fn(f64) -> f64), ("erfc", erfc_cody), ("erfcx", erfcx_cody)] {
+    group.bench_function(name, |b| b.iter(|| inputs.iter().map(|&x| function(black_box(x))).sum::<f64>()));
+  }
+  group.finish();
+}
+
+criterion_group!(benches, bench_normalised_black_call, bench_erf_cody);
+criterion_main!(benches);
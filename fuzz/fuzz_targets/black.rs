@@ -0,0 +1,29 @@
+//!
+//! Black prices of arbitrary forwards, strikes, volatilities and times to expiry.
+//!
+//! Input: f, k, sigma and t as little-endian f64, followed by a byte holding the option type in its lowest bit.
+//!
+
+#![no_main]
+
+use impl_vol::*;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+  if data.len() != 33 {
+    return;
+  }
+  let v: Vec<f64> = data[..32].chunks_exact(8).map(|b| f64::from_le_bytes(b.try_into().unwrap())).collect();
+  let (f, k, sigma, t) = (v[0], v[1], v[2], v[3]);
+  let q = if data[32] & 1 == 0 { 1.0 } else { -1.0 };
+  let price = black(f, k, sigma, t, q);
+  if v.iter().any(|a| a.is_nan()) {
+    assert!(price.is_nan(), "NaN arguments must give NaN, got {}", price);
+    return;
+  }
+  let in_domain = f > 0.0 && f.is_finite() && k > 0.0 && k.is_finite() && sigma >= 0.0 && sigma.is_finite() && t >= 0.0 && t.is_finite();
+  // the price is bounded by  max(f, k),  rounding may carry it beyond the largest finite value
+  if in_domain && f.max(k) <= f64::MAX / 2.0 {
+    assert!(price.is_finite() && price >= 0.0, "price {} of f {}, k {}, sigma {}, t {}, q {}", price, f, k, sigma, t, q);
+  }
+});
@@ -0,0 +1,23 @@
+//!
+//! Cody's complementary error function of arbitrary arguments.
+//!
+//! Input: x as little-endian f64.
+//!
+
+#![no_main]
+
+use impl_vol::*;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+  let Ok(bytes) = data.try_into() else {
+    return;
+  };
+  let x = f64::from_le_bytes(bytes);
+  let y = erfc_cody(x);
+  if x.is_nan() {
+    assert!(y.is_nan(), "erfc(NaN) must be NaN, got {}", y);
+  } else {
+    assert!((0.0..=2.0).contains(&y), "erfc({}) = {}", x, y);
+  }
+});
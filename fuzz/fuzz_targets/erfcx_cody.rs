@@ -0,0 +1,24 @@
+//!
+//! Cody's scaled complementary error function  exp(x²)·erfc(x)  of arbitrary arguments.
+//!
+//! Input: x as little-endian f64.
+//!
+
+#![no_main]
+
+use impl_vol::*;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+  let Ok(bytes) = data.try_into() else {
+    return;
+  };
+  let x = f64::from_le_bytes(bytes);
+  let y = erfcx_cody(x);
+  if x.is_nan() {
+    assert!(y.is_nan(), "erfcx(NaN) must be NaN, got {}", y);
+  } else {
+    // exp(x²)·erfc(x)  exceeds the largest finite value for  x < -26.6,  where it is clamped to it
+    assert!(y.is_finite() && y >= 0.0, "erfcx({}) = {}", x, y);
+  }
+});
@@ -0,0 +1,45 @@
+//!
+//! Implied volatility from arbitrary prices, forwards, strikes and times to expiry.
+//!
+//! Input: price, f, k and t as little-endian f64, followed by a byte holding the option type in its lowest bit
+//! and the maximum number of iterations in the others.
+//!
+
+#![no_main]
+
+use impl_vol::*;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+  if data.len() != 33 {
+    return;
+  }
+  let v: Vec<f64> = data[..32].chunks_exact(8).map(|b| f64::from_le_bytes(b.try_into().unwrap())).collect();
+  let (price, f, k, t) = (v[0], v[1], v[2], v[3]);
+  let q = if data[32] & 1 == 0 { 1.0 } else { -1.0 };
+  let n = (data[32] >> 1) as usize;
+  let sigma = implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(price, f, k, t, q, n);
+  if v.iter().any(|a| a.is_nan()) {
+    assert!(sigma.is_nan(), "NaN arguments must give NaN, got {}", sigma);
+    return;
+  }
+  let in_domain = price >= 0.0 && price.is_finite() && f > 0.0 && f.is_finite() && k > 0.0 && k.is_finite() && t > 0.0 && t.is_finite();
+  let x = (f / k).ln();
+  if in_domain && x.is_finite() {
+    assert!(
+      sigma.is_finite(),
+      "implied volatility {} of price {}, f {}, k {}, t {}, q {}, n {}",
+      sigma,
+      price,
+      f,
+      k,
+      t,
+      q,
+      n
+    );
+    let intrinsic = if q < 0.0 { k - f } else { f - k }.max(0.0);
+    if price > intrinsic && price < if q < 0.0 { k } else { f } {
+      assert!(sigma >= 0.0, "implied volatility {} of price {}, f {}, k {}, t {}, q {}, n {}", sigma, price, f, k, t, q, n);
+    }
+  }
+});
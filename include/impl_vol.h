@@ -0,0 +1,94 @@
+#ifndef IMPL_VOL_H
+#define IMPL_VOL_H
+
+/* Generated with cbindgen from src/ffi.rs, do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Returned by implied volatility functions when the price is below the intrinsic value.
+#define IMPL_VOL_VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC -1.7976931348623157e308
+
+// Returned by implied volatility functions when the price is at or above the maximum price.
+#define IMPL_VOL_VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM 1.7976931348623157e308
+
+// Returned by batch functions on success.
+#define IMPL_VOL_OK 0
+
+// Returned by batch functions when a pointer is null.
+#define IMPL_VOL_NULL_POINTER -1
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Undiscounted Black price of a call (q=1) or put (q=-1).
+double impl_vol_black(double f, double k, double sigma, double t, double q);
+
+// Normalised Black call price for  x = ln(F/K)  and  s = σ·√T.
+double impl_vol_normalised_black_call(double x, double s);
+
+// Normalised Black price of a call (q=1) or put (q=-1).
+double impl_vol_normalised_black(double x, double s, double q);
+
+// Derivative of the normalised Black price with respect to s.
+double impl_vol_normalised_vega(double x, double s);
+
+// Implied volatility from an undiscounted price.
+double impl_vol_implied_volatility_from_a_transformed_rational_guess(double price,
+                                                                     double f,
+                                                                     double k,
+                                                                     double t,
+                                                                     double q);
+
+// Implied volatility from an undiscounted price using at most `n` iterations.
+double impl_vol_implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(double price,
+                                                                                             double f,
+                                                                                             double k,
+                                                                                             double t,
+                                                                                             double q,
+                                                                                             uint32_t n);
+
+// Implied total volatility  s = σ·√T  from a normalised price.
+double impl_vol_normalised_implied_volatility_from_a_transformed_rational_guess(double beta,
+                                                                                double x,
+                                                                                double q);
+
+// Implied total volatility  s = σ·√T  from a normalised price using at most `n` iterations.
+double impl_vol_normalised_implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(double beta,
+                                                                                                        double x,
+                                                                                                        double q,
+                                                                                                        uint32_t n);
+
+// Error function.
+double impl_vol_erf_cody(double x);
+
+// Complementary error function.
+double impl_vol_erfc_cody(double x);
+
+// Scaled complementary error function  exp(x²)·erfc(x).
+double impl_vol_erfcx_cody(double x);
+
+// Computes `n` implied volatilities from arrays of prices, forwards, strikes, expiries and option types.
+//
+// Returns `IMPL_VOL_OK`, or `IMPL_VOL_NULL_POINTER` without writing any results when a pointer is null and `n > 0`.
+//
+// # Safety
+//
+// Each non-null pointer must be valid for `n` consecutive values, and `volatility` must not overlap the inputs.
+int32_t impl_vol_implied_volatility_batch(const double *price,
+                                          const double *f,
+                                          const double *k,
+                                          const double *t,
+                                          const double *q,
+                                          size_t n,
+                                          double *volatility);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* IMPL_VOL_H */
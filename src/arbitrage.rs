@@ -0,0 +1,119 @@
+//!
+//! Static-arbitrage checks for grids of undiscounted call prices.
+//!
+//! For each expiry, call prices must lie within the bounds  max(F-K,0) ≤ C < F  which are also checked by
+//! `implied_volatility_from_a_transformed_rational_guess_with_limited_iterations`, and must be non-increasing
+//! and convex in the strike. Across expiries, the forward-normalised price C/F at the same moneyness K/F
+//! must not decrease with the expiry. Since the forwards differ between expiries, the total implied variance
+//! σ²·T  of each earlier expiry is interpolated linearly in the log-moneyness  ln(K/F)  to the moneyness of the later
+//! quote, and repriced there. Unlike the convex prices, the total variance of a smooth smile is close to linear between
+//! adjacent strikes, so that arbitrage-free grids do not need a tolerance for the interpolation.
+//!
+
+use crate::definitions::*;
+use crate::lets_be_rational::*;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Undiscounted call price at one grid point.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CallPriceQuote {
+  pub strike: f64,
+  pub expiry: f64,
+  pub forward: f64,
+  pub price: f64,
+}
+
+/// Static-arbitrage violation, referring to quotes by their index in the checked slice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArbitrageViolation {
+  /// Price is below the intrinsic value.
+  BelowIntrinsic { index: usize },
+  /// Price is at or above the forward.
+  AboveMaximum { index: usize },
+  /// Price at the higher strike exceeds the price at the lower strike.
+  StrikeMonotonicity { lower: usize, higher: usize },
+  /// Price at the middle strike lies above the chord through its neighbours.
+  StrikeConvexity { left: usize, middle: usize, right: usize },
+  /// Forward-normalised price at the moneyness of the later quote decreases from an earlier to the later expiry.
+  /// The earlier price is interpolated between two strikes, of which `earlier` is the one nearest in moneyness.
+  CalendarMonotonicity { earlier: usize, later: usize },
+}
+
+/// Checks a grid of call prices for static arbitrage and returns all violations.
+/// Price differences within `tolerance` are not reported, including those to the bounds.
+pub fn check_static_arbitrage(quotes: &[CallPriceQuote], tolerance: f64) -> Vec<ArbitrageViolation> {
+  let mut violations = vec![];
+  for (index, quote) in quotes.iter().enumerate() {
+    let intrinsic = max(quote.forward - quote.strike, 0.0);
+    if quote.price < intrinsic - tolerance {
+      violations.push(ArbitrageViolation::BelowIntrinsic { index });
+    }
+    if quote.price >= quote.forward + tolerance {
+      violations.push(ArbitrageViolation::AboveMaximum { index });
+    }
+  }
+  let mut indices: Vec<usize> = (0..quotes.len()).collect();
+  indices.sort_by(|&a, &b| quotes[a].expiry.total_cmp(&quotes[b].expiry).then(quotes[a].strike.total_cmp(&quotes[b].strike)));
+  let slices: Vec<&[usize]> = indices.chunk_by(|&a, &b| quotes[a].expiry == quotes[b].expiry).collect();
+  for slice in &slices {
+    for pair in slice.windows(2) {
+      if quotes[pair[1]].price > quotes[pair[0]].price + tolerance {
+        violations.push(ArbitrageViolation::StrikeMonotonicity { lower: pair[0], higher: pair[1] });
+      }
+    }
+    for triple in slice.windows(3) {
+      let (l, m, r) = (&quotes[triple[0]], &quotes[triple[1]], &quotes[triple[2]]);
+      let w = (m.strike - l.strike) / (r.strike - l.strike);
+      if m.price > (1.0 - w) * l.price + w * r.price + tolerance {
+        violations.push(ArbitrageViolation::StrikeConvexity {
+          left: triple[0],
+          middle: triple[1],
+          right: triple[2],
+        });
+      }
+    }
+  }
+  for (n, later_slice) in slices.iter().enumerate() {
+    for earlier_slice in &slices[..n] {
+      // Strikes in the order of their log-moneyness, with the forward-normalised prices and the total implied variances.
+      let mut earlier_slice: Vec<(f64, f64, f64, usize)> = earlier_slice
+        .iter()
+        .map(|&i| {
+          let e = &quotes[i];
+          let sigma = implied_volatility_from_a_transformed_rational_guess(e.price, e.forward, e.strike, e.expiry, 1.0);
+          (log(e.strike / e.forward), e.price / e.forward, sigma * sigma * e.expiry, i)
+        })
+        .collect();
+      earlier_slice.sort_by(|a, b| a.0.total_cmp(&b.0));
+      for &later in *later_slice {
+        let l = &quotes[later];
+        let moneyness = l.strike / l.forward;
+        let y = log(moneyness);
+        let right = earlier_slice.partition_point(|e| e.0 < y);
+        let (earlier_price, earlier) = if right < earlier_slice.len() && earlier_slice[right].0 == y {
+          (earlier_slice[right].1, earlier_slice[right].3)
+        } else if right == 0 || right == earlier_slice.len() {
+          // Outside the strikes of the earlier expiry.
+          continue;
+        } else {
+          let (a, b) = (earlier_slice[right - 1], earlier_slice[right]);
+          // Prices outside the bounds have no implied volatility, and are reported above.
+          if !(a.2.is_finite() && b.2.is_finite()) {
+            continue;
+          }
+          let w = (y - a.0) / (b.0 - a.0);
+          let total_variance = (1.0 - w) * a.2 + w * b.2;
+          // Deep in the money, the time value is below the rounding of the price, and the repriced value may differ from
+          // the quoted one in the last digits.
+          let earlier_price = black(1.0, moneyness, sqrt(total_variance), 1.0, 1.0) * (1.0 - 4.0 * f64::EPSILON);
+          (earlier_price, if w < 0.5 { a.3 } else { b.3 })
+        };
+        if l.price / l.forward < earlier_price - tolerance / quotes[earlier].forward {
+          violations.push(ArbitrageViolation::CalendarMonotonicity { earlier, later });
+        }
+      }
+    }
+  }
+  violations
+}
@@ -0,0 +1,75 @@
+//!
+//! Implied volatility intervals from bid and ask prices.
+//!
+//! A bid below the intrinsic value is mapped to zero volatility and an ask at or above the maximum price
+//! to infinite volatility, and both cases are flagged. The mid volatility weights the bid and ask volatilities
+//! by their vegas, which vanish at these limits, so that a one-sided quote falls back to its valid side.
+//!
+
+use crate::definitions::*;
+use crate::greeks::*;
+use crate::lets_be_rational::*;
+
+/// Bid/ask implied volatility interval with quality flags.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BidAskImpliedVolatility {
+  pub bid_volatility: f64,
+  pub ask_volatility: f64,
+  /// Vega-weighted mid volatility, NaN when neither side carries vega.
+  pub mid_volatility: f64,
+  /// Bid price is below the intrinsic value.
+  pub bid_below_intrinsic: bool,
+  /// Ask price is at or above the maximum price, i.e. the forward for calls and the strike for puts.
+  pub ask_above_maximum: bool,
+  /// Bid price exceeds the ask price.
+  pub crossed: bool,
+}
+
+impl BidAskImpliedVolatility {
+  /// Returns the width of the volatility interval.
+  pub fn volatility_spread(&self) -> f64 {
+    self.ask_volatility - self.bid_volatility
+  }
+
+  /// Returns `true` when no flag is raised and the volatility spread does not exceed `maximum_volatility_spread`.
+  pub fn is_clean(&self, maximum_volatility_spread: f64) -> bool {
+    !self.bid_below_intrinsic && !self.ask_above_maximum && !self.crossed && self.volatility_spread() <= maximum_volatility_spread
+  }
+}
+
+/// Inverts the undiscounted bid and ask prices of an option and computes the vega-weighted mid volatility.
+pub fn bid_ask_implied_volatility(bid: f64, ask: f64, f: f64, k: f64, t: f64, q: f64 /* q=±1 */) -> BidAskImpliedVolatility {
+  let invert = |price: f64| implied_volatility_from_a_transformed_rational_guess(price, f, k, t, q);
+  let mut bid_volatility = invert(bid);
+  let bid_below_intrinsic = bid_volatility == VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC;
+  if bid_below_intrinsic {
+    bid_volatility = 0.0;
+  } else if bid_volatility == VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM {
+    bid_volatility = f64::INFINITY;
+  }
+  let mut ask_volatility = invert(ask);
+  let ask_above_maximum = ask_volatility == VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM;
+  if ask_above_maximum {
+    ask_volatility = f64::INFINITY;
+  } else if ask_volatility == VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC {
+    ask_volatility = 0.0;
+  }
+  let vega = |volatility: f64| sel(volatility.is_finite(), black_vega(f, k, volatility, t), 0.0);
+  let bid_vega = vega(bid_volatility);
+  let ask_vega = vega(ask_volatility);
+  let mid_volatility = if bid_vega + ask_vega > 0.0 {
+    // Avoid ∞·0 for a side at infinite volatility.
+    let weighted = |vega: f64, volatility: f64| sel(vega > 0.0, vega * volatility, 0.0);
+    (weighted(bid_vega, bid_volatility) + weighted(ask_vega, ask_volatility)) / (bid_vega + ask_vega)
+  } else {
+    f64::NAN
+  };
+  BidAskImpliedVolatility {
+    bid_volatility,
+    ask_volatility,
+    mid_volatility,
+    bid_below_intrinsic,
+    ask_above_maximum,
+    crossed: bid > ask,
+  }
+}
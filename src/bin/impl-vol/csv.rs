@@ -0,0 +1,46 @@
+//!
+//! Minimal reader and writer for delimited text without quoted delimiters.
+//!
+
+use std::io::{BufRead, Write};
+
+/// Delimited table with a header row.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Table {
+  pub header: Vec<String>,
+  pub rows: Vec<Vec<String>>,
+}
+
+impl Table {
+  /// Returns the position of the named column.
+  pub fn column(&self, name: &str) -> Result<usize, String> {
+    self
+      .header
+      .iter()
+      .position(|column| column.eq_ignore_ascii_case(name))
+      .ok_or_else(|| format!("column '{}' not found in header", name))
+  }
+}
+
+/// Reads a table, skipping empty lines.
+pub fn read(reader: impl BufRead, delimiter: char) -> Result<Table, String> {
+  let mut lines = reader.lines().filter(|line| line.as_ref().map_or(true, |line| !line.trim().is_empty()));
+  let header = match lines.next() {
+    Some(line) => split(&line.map_err(|e| e.to_string())?, delimiter),
+    None => return Err("input is empty".to_string()),
+  };
+  let mut rows = vec![];
+  for line in lines {
+    rows.push(split(&line.map_err(|e| e.to_string())?, delimiter));
+  }
+  Ok(Table { header, rows })
+}
+
+/// Writes one row.
+pub fn write_row(writer: &mut impl Write, row: &[String], delimiter: char) -> Result<(), String> {
+  writeln!(writer, "{}", row.join(&delimiter.to_string())).map_err(|e| e.to_string())
+}
+
+fn split(line: &str, delimiter: char) -> Vec<String> {
+  line.split(delimiter).map(|field| field.trim().trim_matches('"').to_string()).collect()
+}
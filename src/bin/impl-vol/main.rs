@@ -0,0 +1,272 @@
+//!
+//! Command-line tool for inverting, pricing and checking options read from CSV.
+//!
+
+mod csv;
+mod output;
+
+use csv::Table;
+use impl_vol::*;
+use output::Format;
+use std::fs::File;
+use std::io::{stdin, stdout, BufReader, BufWriter, Write};
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Usage: impl-vol [COMMAND] [OPTIONS] [FILE]
+
+Reads rows of option data from FILE, or standard input when FILE is omitted or '-',
+and writes them with the results of COMMAND appended. Prices are undiscounted.
+
+Commands:
+  invert     Implied volatility from the price: implied_volatility, status, iterations [default]
+  price      Black price from the volatility: price
+  greeks     Black price and sensitivities: price, delta, gamma, vega, theta, vanna, volga
+  roundtrip  Price from the volatility, then invert: price, implied_volatility, relative_error, status
+
+Options:
+  -o, --output FILE             Write to FILE instead of standard output
+  -f, --format FORMAT           Output format: csv, json or table [default: csv]
+  -d, --delimiter CHAR          Field delimiter of the input and of csv output [default: ,]
+      --price-column NAME       Column holding the price [default: price]
+      --volatility-column NAME  Column holding the volatility [default: volatility]
+      --forward-column NAME     Column holding the forward [default: forward]
+      --strike-column NAME      Column holding the strike [default: strike]
+      --expiry-column NAME      Column holding the time to expiry in years [default: expiry]
+      --type-column NAME        Column holding the option type: call, c, 1 or put, p, -1 [default: type]
+  -h, --help                    Print this help
+";
+
+/// Subcommand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+  Invert,
+  Price,
+  Greeks,
+  Roundtrip,
+}
+
+impl Command {
+  fn parse(name: &str) -> Option<Command> {
+    match name {
+      "invert" => Some(Command::Invert),
+      "price" => Some(Command::Price),
+      "greeks" => Some(Command::Greeks),
+      "roundtrip" => Some(Command::Roundtrip),
+      _ => None,
+    }
+  }
+
+  /// Names of the appended columns.
+  fn columns(&self) -> &'static [&'static str] {
+    match self {
+      Command::Invert => &["implied_volatility", "status", "iterations"],
+      Command::Price => &["price"],
+      Command::Greeks => &["price", "delta", "gamma", "vega", "theta", "vanna", "volga"],
+      Command::Roundtrip => &["price", "implied_volatility", "relative_error", "status"],
+    }
+  }
+}
+
+/// Command line options.
+struct Options {
+  command: Command,
+  input: Option<String>,
+  output: Option<String>,
+  format: Format,
+  delimiter: char,
+  price_column: String,
+  volatility_column: String,
+  forward_column: String,
+  strike_column: String,
+  expiry_column: String,
+  type_column: String,
+}
+
+impl Default for Options {
+  fn default() -> Self {
+    Self {
+      command: Command::Invert,
+      input: None,
+      output: None,
+      format: Format::Csv,
+      delimiter: ',',
+      price_column: "price".to_string(),
+      volatility_column: "volatility".to_string(),
+      forward_column: "forward".to_string(),
+      strike_column: "strike".to_string(),
+      expiry_column: "expiry".to_string(),
+      type_column: "type".to_string(),
+    }
+  }
+}
+
+/// Parses the command line arguments, returns `None` when help was requested.
+fn parse_options(args: impl IntoIterator<Item = String>) -> Result<Option<Options>, String> {
+  let mut options = Options::default();
+  let mut args = args.into_iter().peekable();
+  if let Some(command) = args.peek().and_then(|arg| Command::parse(arg)) {
+    options.command = command;
+    args.next();
+  }
+  while let Some(arg) = args.next() {
+    let mut value = |name: &str| args.next().ok_or_else(|| format!("missing value for {}", name));
+    match arg.as_str() {
+      "-h" | "--help" => return Ok(None),
+      "-o" | "--output" => options.output = Some(value(&arg)?),
+      "-f" | "--format" => {
+        let format = value(&arg)?;
+        options.format = Format::parse(&format).ok_or_else(|| format!("unknown format '{}'", format))?;
+      }
+      "-d" | "--delimiter" => {
+        let delimiter = value(&arg)?;
+        let mut chars = delimiter.chars();
+        options.delimiter = match (chars.next(), chars.next()) {
+          (Some(c), None) => c,
+          _ if delimiter == "\\t" => '\t',
+          _ => return Err(format!("invalid delimiter '{}'", delimiter)),
+        };
+      }
+      "--price-column" => options.price_column = value(&arg)?,
+      "--volatility-column" => options.volatility_column = value(&arg)?,
+      "--forward-column" => options.forward_column = value(&arg)?,
+      "--strike-column" => options.strike_column = value(&arg)?,
+      "--expiry-column" => options.expiry_column = value(&arg)?,
+      "--type-column" => options.type_column = value(&arg)?,
+      _ if arg.starts_with('-') && arg != "-" => return Err(format!("unknown option '{}'", arg)),
+      _ if options.input.is_none() => options.input = Some(arg),
+      _ => return Err(format!("unexpected argument '{}'", arg)),
+    }
+  }
+  Ok(Some(options))
+}
+
+/// Parses the option type into q=±1.
+fn parse_option_type(value: &str) -> Option<f64> {
+  match value.to_ascii_lowercase().as_str() {
+    "call" | "c" | "1" | "+1" => Some(1.0),
+    "put" | "p" | "-1" => Some(-1.0),
+    _ => None,
+  }
+}
+
+/// Option data of one row: the price or volatility, forward, strike, expiry and q=±1.
+type Inputs = (f64, f64, f64, f64, f64);
+
+/// Inverts the price of a validated quote, recording the number of iterations, or `None` when the quote is invalid.
+fn invert(price: f64, f: f64, k: f64, t: f64, q: f64) -> Option<SolveResult> {
+  let quote = OptionQuote {
+    kind: OptionKind::from_q(q)?,
+    forward: Forward::new(f)?,
+    strike: Strike::new(k)?,
+    expiry: Expiry::new(t)?,
+    price,
+  };
+  Some(quote.solve())
+}
+
+/// Returns the implied volatility, or an empty field with the status for sentinel values and invalid quotes.
+fn volatility_and_status(volatility: Option<f64>) -> (String, &'static str) {
+  match volatility {
+    None => (String::new(), "invalid_input"),
+    Some(volatility) if volatility == VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC => (String::new(), "below_intrinsic"),
+    Some(volatility) if volatility == VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM => (String::new(), "above_maximum"),
+    Some(volatility) => (volatility.to_string(), "ok"),
+  }
+}
+
+/// Evaluates the command for one row.
+fn evaluate(command: Command, (value, f, k, t, q): Inputs) -> Vec<String> {
+  match command {
+    Command::Invert => {
+      let result = invert(value, f, k, t, q);
+      let (volatility, status) = volatility_and_status(result.map(|result| result.volatility));
+      vec![volatility, status.to_string(), result.map(|result| result.iterations.to_string()).unwrap_or_default()]
+    }
+    Command::Price => vec![black(f, k, value, t, q).to_string()],
+    Command::Greeks => {
+      let greeks = black_greeks(f, k, value, t, q);
+      [greeks.price, greeks.delta, greeks.gamma, greeks.vega, greeks.theta, greeks.vanna, greeks.volga]
+        .iter()
+        .map(f64::to_string)
+        .collect()
+    }
+    Command::Roundtrip => {
+      let price = black(f, k, value, t, q);
+      let volatility = invert(price, f, k, t, q).map(|result| result.volatility);
+      let (implied_volatility, status) = volatility_and_status(volatility);
+      let relative_error = match volatility {
+        Some(volatility) if status == "ok" => ((volatility - value) / value).to_string(),
+        _ => String::new(),
+      };
+      vec![price.to_string(), implied_volatility, relative_error, status.to_string()]
+    }
+  }
+}
+
+fn run(options: Options) -> Result<(), String> {
+  let input = match options.input.as_deref() {
+    None | Some("-") => csv::read(stdin().lock(), options.delimiter)?,
+    Some(path) => csv::read(BufReader::new(File::open(path).map_err(|e| format!("{}: {}", path, e))?), options.delimiter)?,
+  };
+  let value_column = if options.command == Command::Invert {
+    &options.price_column
+  } else {
+    &options.volatility_column
+  };
+  let columns = [
+    input.column(value_column)?,
+    input.column(&options.forward_column)?,
+    input.column(&options.strike_column)?,
+    input.column(&options.expiry_column)?,
+    input.column(&options.type_column)?,
+  ];
+  let appended = options.command.columns();
+  let mut table = Table {
+    header: input.header.clone(),
+    rows: Vec::with_capacity(input.rows.len()),
+  };
+  table.header.extend(appended.iter().map(|name| name.to_string()));
+  for row in &input.rows {
+    let field = |i: usize| row.get(columns[i]).map(String::as_str).unwrap_or_default();
+    let number = |i: usize| field(i).parse::<f64>().ok();
+    let mut output = row.clone();
+    output.resize(input.header.len(), String::new());
+    match (number(0), number(1), number(2), number(3), parse_option_type(field(4))) {
+      (Some(value), Some(f), Some(k), Some(t), Some(q)) => output.extend(evaluate(options.command, (value, f, k, t, q))),
+      _ => {
+        output.resize(table.header.len(), String::new());
+        if let Some(status) = appended.iter().position(|&name| name == "status") {
+          output[input.header.len() + status] = "invalid_input".to_string();
+        }
+      }
+    }
+    table.rows.push(output);
+  }
+  let mut writer: Box<dyn Write> = match options.output.as_deref() {
+    None | Some("-") => Box::new(BufWriter::new(stdout().lock())),
+    Some(path) => Box::new(BufWriter::new(File::create(path).map_err(|e| format!("{}: {}", path, e))?)),
+  };
+  output::write(&mut writer, &table, options.format, options.delimiter)?;
+  writer.flush().map_err(|e| e.to_string())
+}
+
+fn main() -> ExitCode {
+  match parse_options(std::env::args().skip(1)) {
+    Ok(None) => {
+      print!("{}", USAGE);
+      ExitCode::SUCCESS
+    }
+    Ok(Some(options)) => match run(options) {
+      Ok(()) => ExitCode::SUCCESS,
+      Err(reason) => {
+        eprintln!("impl-vol: {}", reason);
+        ExitCode::FAILURE
+      }
+    },
+    Err(reason) => {
+      eprintln!("impl-vol: {}\n\n{}", reason, USAGE);
+      ExitCode::from(2)
+    }
+  }
+}
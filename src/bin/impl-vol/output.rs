@@ -0,0 +1,94 @@
+//!
+//! Rendering of result tables as CSV, JSON or aligned text.
+//!
+
+use crate::csv::{self, Table};
+use std::io::Write;
+
+/// Output format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+  Csv,
+  Json,
+  Table,
+}
+
+impl Format {
+  /// Parses the format name.
+  pub fn parse(name: &str) -> Option<Format> {
+    match name {
+      "csv" => Some(Format::Csv),
+      "json" => Some(Format::Json),
+      "table" => Some(Format::Table),
+      _ => None,
+    }
+  }
+}
+
+/// Writes the table in the given format.
+pub fn write(writer: &mut impl Write, table: &Table, format: Format, delimiter: char) -> Result<(), String> {
+  match format {
+    Format::Csv => {
+      csv::write_row(writer, &table.header, delimiter)?;
+      for row in &table.rows {
+        csv::write_row(writer, row, delimiter)?;
+      }
+      Ok(())
+    }
+    Format::Json => write_json(writer, table).map_err(|e| e.to_string()),
+    Format::Table => write_text(writer, table).map_err(|e| e.to_string()),
+  }
+}
+
+/// Writes an array of objects, emitting finite numbers as JSON numbers, empty fields as null and anything else as strings.
+fn write_json(writer: &mut impl Write, table: &Table) -> std::io::Result<()> {
+  writeln!(writer, "[")?;
+  for (i, row) in table.rows.iter().enumerate() {
+    let fields: Vec<String> = table
+      .header
+      .iter()
+      .zip(row.iter().map(String::as_str).chain(std::iter::repeat("")))
+      .map(|(name, value)| format!("{}: {}", json_string(name), json_value(value)))
+      .collect();
+    writeln!(writer, "  {{{}}}{}", fields.join(", "), if i + 1 < table.rows.len() { "," } else { "" })?;
+  }
+  writeln!(writer, "]")
+}
+
+fn json_value(value: &str) -> String {
+  match value.parse::<f64>() {
+    _ if value.is_empty() => "null".to_string(),
+    Ok(number) if number.is_finite() => number.to_string(),
+    _ => json_string(value),
+  }
+}
+
+fn json_string(value: &str) -> String {
+  let mut quoted = String::with_capacity(value.len() + 2);
+  quoted.push('"');
+  for c in value.chars() {
+    match c {
+      '"' => quoted.push_str("\\\""),
+      '\\' => quoted.push_str("\\\\"),
+      c if (c as u32) < 0x20 => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+      c => quoted.push(c),
+    }
+  }
+  quoted.push('"');
+  quoted
+}
+
+/// Writes right-aligned columns separated by two spaces.
+fn write_text(writer: &mut impl Write, table: &Table) -> std::io::Result<()> {
+  let mut widths: Vec<usize> = table.header.iter().map(|name| name.chars().count()).collect();
+  for row in &table.rows {
+    for (width, value) in widths.iter_mut().zip(row) {
+      *width = (*width).max(value.chars().count());
+    }
+  }
+  for row in std::iter::once(&table.header).chain(&table.rows) {
+    let fields: Vec<String> = widths.iter().zip(row).map(|(width, value)| format!("{:>width$}", value, width = width)).collect();
+    writeln!(writer, "{}", fields.join("  "))?;
+  }
+  Ok(())
+}
@@ -1,4 +1,4 @@
-use lazy_static::lazy_static;
+use crate::float::Float;
 
 pub const VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC: f64 = f64::MIN;
 pub const VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM: f64 = f64::MAX;
@@ -6,24 +6,19 @@ pub const DBL_MIN: f64 = f64::MIN_POSITIVE;
 pub const DBL_MAX: f64 = f64::MAX;
 pub const DBL_EPSILON: f64 = f64::EPSILON;
 
-lazy_static! {
-  pub static ref SQRT_DBL_EPSILON: f64 = DBL_EPSILON.sqrt();
-  pub static ref FOURTH_ROOT_DBL_EPSILON: f64 = SQRT_DBL_EPSILON.sqrt();
-  pub static ref EIGHTH_ROOT_DBL_EPSILON: f64 = FOURTH_ROOT_DBL_EPSILON.sqrt();
-  pub static ref SIXTEENTH_ROOT_DBL_EPSILON: f64 = EIGHTH_ROOT_DBL_EPSILON.sqrt();
-  pub static ref SQRT_DBL_MIN: f64 = DBL_MIN.sqrt();
-  pub static ref SQRT_DBL_MAX: f64 = DBL_MAX.sqrt();
-  pub static ref ASYMPTOTIC_EXPANSION_ACCURACY_THRESHOLD: f64 = -10.0;
-  pub static ref SMALL_T_EXPANSION_OF_NORMALISED_BLACK_THRESHOLD: f64 = 2.0 * *SIXTEENTH_ROOT_DBL_EPSILON;
-}
+pub(crate) const SQRT_DBL_EPSILON: f64 = 1.4901161193847656e-8;
+pub(crate) const FOURTH_ROOT_DBL_EPSILON: f64 = 0.0001220703125;
+pub(crate) const SIXTEENTH_ROOT_DBL_EPSILON: f64 = 0.10511205190671433;
+pub(crate) const SQRT_DBL_MIN: f64 = 1.4916681462400413e-154;
+pub(crate) const SQRT_DBL_MAX: f64 = 1.3407807929942596e154;
 
 #[inline(always)]
-pub fn is_zero(x: f64) -> bool {
-  x.abs() < DBL_MIN
+pub fn is_zero<T: Float>(x: T) -> bool {
+  x.abs() < T::MIN_POSITIVE
 }
 
 #[inline(always)]
-pub fn max(x: f64, y: f64) -> f64 {
+pub fn max<T: Float>(x: T, y: T) -> T {
   if x >= y {
     x
   } else {
@@ -32,37 +27,37 @@ pub fn max(x: f64, y: f64) -> f64 {
 }
 
 #[inline(always)]
-pub fn exp(x: f64) -> f64 {
+pub fn exp<T: Float>(x: T) -> T {
   x.exp()
 }
 
 #[inline(always)]
-pub fn pow(x: f64, n: f64) -> f64 {
+pub fn pow<T: Float>(x: T, n: T) -> T {
   x.powf(n)
 }
 
 #[inline(always)]
-pub fn log(x: f64) -> f64 {
+pub fn log<T: Float>(x: T) -> T {
   x.ln()
 }
 
 #[inline(always)]
-pub fn sqrt(x: f64) -> f64 {
+pub fn sqrt<T: Float>(x: T) -> T {
   x.sqrt()
 }
 
 #[inline(always)]
-pub fn square(x: f64) -> f64 {
+pub fn square<T: Float>(x: T) -> T {
   x * x
 }
 
 #[inline(always)]
-pub fn fabs(x: f64) -> f64 {
+pub fn fabs<T: Float>(x: T) -> T {
   x.abs()
 }
 
 #[inline(always)]
-pub fn sel(c: bool, x: f64, y: f64) -> f64 {
+pub fn sel<T: Float>(c: bool, x: T, y: T) -> T {
   if c {
     x
   } else {
@@ -71,17 +66,19 @@ pub fn sel(c: bool, x: f64, y: f64) -> f64 {
 }
 
 #[inline(always)]
-pub fn d_int(x: f64) -> f64 {
-  if x > 0.0 {
+pub fn d_int<T: Float>(x: T) -> T {
+  if x > T::ZERO {
     x.floor()
   } else {
-    -((-x).floor())
+    -(-x).floor()
   }
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
+  #[cfg(not(feature = "std"))]
+  extern crate std;
 
   #[test]
   fn test_max() {
@@ -91,12 +88,29 @@ mod tests {
   }
 
   #[test]
+  #[cfg(feature = "std")]
   fn test_exp() {
     assert_eq!((0.0_f64).exp(), exp(0.0));
     assert_eq!((1.0_f64).exp(), exp(1.0));
     assert_eq!((-1.0_f64).exp(), exp(-1.0));
   }
 
+  /// The `no_std` build uses the `libm` math functions, which may differ from the `std` ones in the last bit.
+  #[test]
+  #[cfg(all(feature = "std", feature = "libm"))]
+  fn test_libm_functions_against_std() {
+    let ulps = |expected: f64, actual: f64| (expected.to_bits() as i64 - actual.to_bits() as i64).unsigned_abs();
+    for i in -2000..=2000 {
+      let x = i as f64 * 0.0173;
+      assert!(ulps(x.exp(), libm::exp(x)) <= 1, "exp({})", x);
+      assert_eq!(x.floor(), libm::floor(x), "floor({})", x);
+      let y = fabs(x) + DBL_MIN;
+      assert!(ulps(y.ln(), libm::log(y)) <= 1, "log({})", y);
+      assert_eq!(y.sqrt(), libm::sqrt(y), "sqrt({})", y);
+      assert!(ulps(y.powf(0.3), libm::pow(y, 0.3)) <= 1, "pow({}, 0.3)", y);
+    }
+  }
+
   #[test]
   fn test_sqrt() {
     assert_eq!((0.0_f64).sqrt(), sqrt(0.0));
@@ -0,0 +1,144 @@
+//!
+//! Option deltas in the forward, spot and premium-adjusted conventions, and their inversion to strikes.
+//!
+//! With  s = σ·√T,  d1 = ln(F/K)/s + s/2  and  d2 = d1 - s,  the deltas of a call (q=1) or put (q=-1) are
+//!
+//! ```text
+//!     forward                       Δ  =  q·Φ(q·d1)
+//!     spot                          Δ  =  q·Φ(q·d1)·DF_foreign
+//!     premium-adjusted forward      Δ  =  q·(K/F)·Φ(q·d2)
+//!     premium-adjusted spot         Δ  =  q·(K/F)·Φ(q·d2)·DF_foreign
+//! ```
+//!
+
+use crate::definitions::*;
+use crate::normal_distribution::*;
+
+/// Maximum number of iterations of the safeguarded Newton search.
+const DELTA_INVERSION_MAXIMUM_ITERATIONS: usize = 100;
+
+/// Delta conventions used for quoting options, in particular in FX markets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeltaConvention {
+  /// Derivative of the undiscounted price with respect to the forward.
+  Forward,
+  /// Forward delta multiplied by the foreign discount factor.
+  Spot,
+  /// Forward delta less the premium expressed in units of the foreign currency.
+  PremiumAdjustedForward,
+  /// Premium-adjusted forward delta multiplied by the foreign discount factor.
+  PremiumAdjustedSpot,
+}
+
+impl DeltaConvention {
+  /// Returns `true` when the premium is included in the delta.
+  pub fn is_premium_adjusted(&self) -> bool {
+    matches!(self, DeltaConvention::PremiumAdjustedForward | DeltaConvention::PremiumAdjustedSpot)
+  }
+
+  /// Returns `true` when the delta is expressed against spot.
+  pub fn is_spot(&self) -> bool {
+    matches!(self, DeltaConvention::Spot | DeltaConvention::PremiumAdjustedSpot)
+  }
+}
+
+/// Returns the delta of an option in the given convention.
+/// Put deltas are negative. `df_foreign` is only used for spot conventions.
+pub fn black_delta(f: f64, k: f64, sigma: f64, t: f64, q: f64 /* q=±1 */, df_foreign: f64, convention: DeltaConvention) -> f64 {
+  let s = sigma * sqrt(t);
+  let q = sel(q < 0.0, -1.0, 1.0);
+  let d1 = log(f / k) / s + 0.5 * s;
+  let delta = if convention.is_premium_adjusted() {
+    q * (k / f) * norm_cdf(q * (d1 - s))
+  } else {
+    q * norm_cdf(q * d1)
+  };
+  sel(convention.is_spot(), df_foreign * delta, delta)
+}
+
+/// Returns the strike for the given signed delta, or NaN when no such strike exists.
+///
+/// Forward and spot deltas are inverted in closed form. Premium-adjusted deltas are found by a bracketed search.
+/// The premium-adjusted call delta is not monotone in the strike and attains a maximum below one;
+/// the strike to the right of this maximum is returned, and NaN when the delta exceeds the maximum.
+/// The premium-adjusted put delta is unbounded in magnitude, and may exceed one for high strikes.
+pub fn strike_from_delta(delta: f64, f: f64, sigma: f64, t: f64, q: f64 /* q=±1 */, df_foreign: f64, convention: DeltaConvention) -> f64 {
+  let s = sigma * sqrt(t);
+  let q = sel(q < 0.0, -1.0, 1.0);
+  let target = q * sel(convention.is_spot(), delta / df_foreign, delta);
+  let maximum = sel(convention.is_premium_adjusted() && q < 0.0, f64::INFINITY, 1.0);
+  if !(target > 0.0 && target < maximum && s > 0.0) {
+    return f64::NAN;
+  }
+  // Strike for the same delta without premium adjustment, from  |Δ| = Φ(q·d1),  which only exists below one.
+  let d2 = sel(target < 1.0, q * inverse_norm_cdf(target) - s, f64::NAN);
+  if convention.is_premium_adjusted() {
+    premium_adjusted_d2(target, s, q, d2).map_or(f64::NAN, |d2| f * exp(-s * d2 - 0.5 * s * s))
+  } else {
+    f * exp(-s * d2 - 0.5 * s * s)
+  }
+}
+
+///```text
+/// Solves  |Δ(d2)| = exp(-s·d2-s²/2)·Φ(q·d2) = target  for d2.
+///
+/// The objective  h(d2) = q·(|Δ(d2)| - target)  is increasing for puts, and for calls left of the maximum
+/// of |Δ| at d2 = z* where  φ(z*) = s·Φ(z*). Including the premium lowers |Δ| for a given strike,
+/// so the solution lies above the d2 of the unadjusted delta given as `d2_unadjusted`, which is NaN for put deltas beyond one.
+///```
+fn premium_adjusted_d2(target: f64, s: f64, q: f64 /* q=±1 */, d2_unadjusted: f64) -> Option<f64> {
+  let h = |d2: f64| {
+    let e = exp(-s * d2 - 0.5 * s * s);
+    (q * (e * norm_cdf(q * d2) - target), e * (norm_pdf(d2) - q * s * norm_cdf(q * d2)))
+  };
+  let mut upper = d2_unadjusted + 1.0;
+  let mut lower = d2_unadjusted;
+  if q > 0.0 {
+    // Location of the maximum, from  s·Φ(z)-φ(z) = 0  with derivative  φ(z)·(s+z),  using φ(z)/Φ(z) > -z.
+    let mut z_upper = 1.0 - s;
+    while s * norm_cdf(z_upper) <= norm_pdf(z_upper) {
+      z_upper += 1.0;
+    }
+    upper = safeguarded_newton(-s, z_upper, |z| (s * norm_cdf(z) - norm_pdf(z), norm_pdf(z) * (s + z)));
+    if h(upper).0 < 0.0 {
+      return None;
+    }
+  } else if target < 1.0 {
+    while h(upper).0 < 0.0 {
+      upper += 1.0;
+    }
+  } else {
+    // Without unadjusted strike, bracket from where the discount term alone equals the target, which makes  |Δ| < target  as  Φ(-d2) < 1.
+    upper = -(log(target) + 0.5 * s * s) / s;
+    lower = upper - 1.0;
+    while h(lower).0 > 0.0 {
+      lower -= 1.0;
+    }
+  }
+  Some(safeguarded_newton(lower.min(upper), upper, h))
+}
+
+/// Newton iteration for the root of an increasing function with  g(lower) ≤ 0 ≤ g(upper),
+/// tightening the bracket on each step and bisecting whenever a step leaves it.
+fn safeguarded_newton(mut lower: f64, mut upper: f64, g: impl Fn(f64) -> (f64, f64)) -> f64 {
+  let mut z = 0.5 * (lower + upper);
+  for _ in 0..DELTA_INVERSION_MAXIMUM_ITERATIONS {
+    let (value, derivative) = g(z);
+    if value < 0.0 {
+      lower = z;
+    } else if value > 0.0 {
+      upper = z;
+    } else {
+      return z;
+    }
+    let mut next = z - value / derivative;
+    if !(next > lower && next < upper) {
+      next = 0.5 * (lower + upper);
+    }
+    if fabs(next - z) <= DBL_EPSILON * max(fabs(z), 1.0) {
+      return next;
+    }
+    z = next;
+  }
+  z
+}
@@ -0,0 +1,49 @@
+//!
+//! Black prices and implied volatilities for discounted premiums.
+//!
+//! The premium of a European option is its undiscounted price times the discount factor  D  to the payment date,
+//! so that it is bounded by
+//!
+//! ```text
+//!     D·max(q·(F-K), 0)  ≤  premium  <  D·F  for calls,  D·K  for puts.
+//! ```
+//!
+//! The bounds are checked on the premium itself, before it is divided by  D,  so that a premium at the discounted
+//! intrinsic value is not pushed below the intrinsic value by the rounding of the division. Next to the maximum price,
+//! where the implied volatility grows without bound, the rounded undiscounted price may still reach the maximum.
+//!
+
+use crate::definitions::*;
+use crate::float::Float;
+use crate::lets_be_rational::*;
+
+/// Black premium of a call (q=1) or put (q=-1), i.e. the undiscounted price times the discount factor `df`.
+pub fn discounted_black<T: Float>(f: T, k: T, sigma: T, t: T, q: T /* q=±1 */, df: T) -> T {
+  df * black(f, k, sigma, t, q)
+}
+
+/// Implied volatility from the `premium` of a call (q=1) or put (q=-1) and the discount factor `df` to its payment date.
+///
+/// Premiums below the discounted intrinsic value give [VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC], premiums
+/// at or above the discounted maximum price give [VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM].
+/// NaN arguments and discount factors that are not positive and finite give NaN.
+pub fn implied_volatility_from_discounted_price<T: Float>(premium: T, f: T, k: T, t: T, q: T /* q=±1 */, df: T) -> T {
+  if premium.is_nan() || f.is_nan() || k.is_nan() || t.is_nan() || q.is_nan() || !(df > T::ZERO && df <= T::MAX) {
+    return T::NAN;
+  }
+  let intrinsic = fabs(max(sel(q < T::ZERO, k - f, f - k), T::ZERO));
+  if premium < df * intrinsic {
+    return implied_volatility_output(0, -T::MAX);
+  }
+  if premium >= df * sel(q < T::ZERO, k, f) {
+    return implied_volatility_output(0, T::MAX);
+  }
+  // Within the bounds, the undiscounted price may only round below the intrinsic value.
+  implied_volatility_from_a_transformed_rational_guess(max(premium / df, intrinsic), f, k, t, q)
+}
+
+/// Implied volatility from the `premium` of a call (q=1) or put (q=-1) paid at expiry, discounted with the continuously
+/// compounded rate `r` over the time to expiry `t`, see [implied_volatility_from_discounted_price].
+pub fn implied_volatility_from_discounted_price_and_rate<T: Float>(premium: T, f: T, k: T, t: T, q: T /* q=±1 */, r: T) -> T {
+  implied_volatility_from_discounted_price(premium, f, k, t, q, exp(-r * t))
+}
@@ -8,10 +8,11 @@
 //!
 
 use crate::definitions::*;
+use crate::float::Float;
 
 /// SUBROUTINE CALERF(ARG,RESULT,JINT)
 #[allow(clippy::excessive_precision)]
-fn calerf(x: f64, jint: i64) -> f64 {
+fn calerf<T: Float>(x: T, jint: i64) -> T {
   let a = [3.1611237438705656, 113.864154151050156, 377.485237685302021, 3209.37758913846947, 0.185777706184603153];
   let b = [23.6012909523441209, 244.024637934444173, 1282.61652607737228, 2844.23683343917062];
   let c__ = [
@@ -51,38 +52,38 @@ fn calerf(x: f64, jint: i64) -> f64 {
     0.00233520497626869185,
   ];
 
-  const ZERO: f64 = 0.0;
-  const HALF: f64 = 0.5;
-  const ONE: f64 = 1.0;
-  const TWO: f64 = 2.0;
-  const FOUR: f64 = 4.0;
-  const SQRPI: f64 = 0.56418958354775628695;
-  const THRESH: f64 = 0.46875;
-  const SIXTEN: f64 = 16.0;
+  let zero = T::ZERO;
+  let half = T::HALF;
+  let one = T::ONE;
+  let two = T::TWO;
+  let four = T::from_f64(4.0);
+  let sqrpi = T::from_f64(0.56418958354775628695);
+  let thresh = T::from_f64(0.46875);
+  let sixten = T::from_f64(16.0);
 
-  const XINF: f64 = 1.79e308;
-  const XNEG: f64 = -26.628;
-  const XSMALL: f64 = 1.11e-16;
-  const XBIG: f64 = 26.543;
-  const XHUGE: f64 = 6.71e7;
-  const XMAX: f64 = 2.53e307;
+  let xinf = T::ERF_XINF;
+  let xneg = T::ERF_XNEG;
+  let xsmall = T::ERF_XSMALL;
+  let xbig = T::ERF_XBIG;
+  let xhuge = T::ERF_XHUGE;
+  let xmax = T::ERF_XMAX;
 
-  let fix_negative_result = |mut result: f64| {
+  let fix_negative_result = |mut result: T| {
     if jint == 0 {
-      result = (HALF - result) + HALF;
-      if x < ZERO {
+      result = (half - result) + half;
+      if x < zero {
         result = -result;
       }
     } else if jint == 1 {
-      if x < ZERO {
-        result = TWO - result;
+      if x < zero {
+        result = two - result;
       }
-    } else if x < ZERO {
-      if x < XNEG {
-        result = XINF;
+    } else if x < zero {
+      if x < xneg {
+        result = xinf;
       } else {
-        let d_1 = x * SIXTEN;
-        let ysq = d_int(d_1) / SIXTEN;
+        let d_1 = x * sixten;
+        let ysq = d_int(d_1) / sixten;
         let del = (x - ysq) * (x + ysq);
         let y = exp(ysq * ysq) * exp(del);
         result = y + y - result;
@@ -92,64 +93,64 @@ fn calerf(x: f64, jint: i64) -> f64 {
   };
 
   let y = fabs(x);
-  if y <= THRESH {
-    let mut ysq = ZERO;
-    if y > XSMALL {
+  if y <= thresh {
+    let mut ysq = zero;
+    if y > xsmall {
       ysq = y * y;
     }
-    let mut xnum = a[4] * ysq;
+    let mut xnum = T::from_f64(a[4]) * ysq;
     let mut xden = ysq;
     for i in 1..=3 {
-      xnum = (xnum + a[i - 1]) * ysq;
-      xden = (xden + b[i - 1]) * ysq;
+      xnum = (xnum + T::from_f64(a[i - 1])) * ysq;
+      xden = (xden + T::from_f64(b[i - 1])) * ysq;
     }
-    let mut result = x * (xnum + a[3]) / (xden + b[3]);
+    let mut result = x * (xnum + T::from_f64(a[3])) / (xden + T::from_f64(b[3]));
     if jint != 0 {
-      result = ONE - result;
+      result = one - result;
     }
     if jint == 2 {
       result *= exp(ysq);
     }
     result
-  } else if y <= FOUR {
-    let mut xnum = c__[8] * y;
+  } else if y <= four {
+    let mut xnum = T::from_f64(c__[8]) * y;
     let mut xden = y;
     for i in 1..=7 {
-      xnum = (xnum + c__[i - 1]) * y;
-      xden = (xden + d__[i - 1]) * y;
+      xnum = (xnum + T::from_f64(c__[i - 1])) * y;
+      xden = (xden + T::from_f64(d__[i - 1])) * y;
     }
-    let mut result = (xnum + c__[7]) / (xden + d__[7]);
+    let mut result = (xnum + T::from_f64(c__[7])) / (xden + T::from_f64(d__[7]));
     if jint != 2 {
-      let mut d_1 = y * SIXTEN;
-      let ysq = d_int(d_1) / SIXTEN;
+      let mut d_1 = y * sixten;
+      let ysq = d_int(d_1) / sixten;
       let del = (y - ysq) * (y + ysq);
       d_1 = exp(-ysq * ysq) * exp(-del);
       result *= d_1;
     }
     fix_negative_result(result)
   } else {
-    let mut result = ZERO;
-    if y >= XBIG {
-      if jint != 2 || y >= XMAX {
+    let mut result = zero;
+    if y >= xbig {
+      if jint != 2 || y >= xmax {
         return fix_negative_result(result);
       }
-      if y >= XHUGE {
-        result = SQRPI / y;
+      if y >= xhuge {
+        result = sqrpi / y;
         return fix_negative_result(result);
       }
     }
-    let ysq = ONE / (y * y);
-    let mut xnum = p[5] * ysq;
+    let ysq = one / (y * y);
+    let mut xnum = T::from_f64(p[5]) * ysq;
     let mut xden = ysq;
     for i in 1..=4 {
-      xnum = (xnum + p[i - 1]) * ysq;
-      xden = (xden + q[i - 1]) * ysq;
+      xnum = (xnum + T::from_f64(p[i - 1])) * ysq;
+      xden = (xden + T::from_f64(q[i - 1])) * ysq;
     }
-    result = ysq * (xnum + p[4]) / (xden + q[4]);
-    result = (SQRPI - result) / y;
+    result = ysq * (xnum + T::from_f64(p[4])) / (xden + T::from_f64(q[4]));
+    result = (sqrpi - result) / y;
     if jint != 2 {
-      let mut d_1 = y * SIXTEN;
-      let ysq = d_int(d_1) / SIXTEN;
+      let mut d_1 = y * sixten;
+      let ysq = d_int(d_1) / sixten;
       let del = (y - ysq) * (y + ysq);
       d_1 = exp(-ysq * ysq) * exp(-del);
       result *= d_1;
@@ -158,14 +159,14 @@ fn calerf(x: f64, jint: i64) -> f64 {
   }
 }
 
-pub fn erf_cody(x: f64) -> f64 {
+pub fn erf_cody<T: Float>(x: T) -> T {
   calerf(x, 0)
 }
 
-pub fn erfc_cody(x: f64) -> f64 {
+pub fn erfc_cody<T: Float>(x: T) -> T {
   calerf(x, 1)
 }
 
-pub fn erfcx_cody(x: f64) -> f64 {
+pub fn erfcx_cody<T: Float>(x: T) -> T {
   calerf(x, 2)
 }
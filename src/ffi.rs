@@ -0,0 +1,119 @@
+//!
+//! C ABI for the pricing and implied volatility functions.
+//!
+//! The shared and static libraries are built with `task ffi`, the corresponding header `include/impl_vol.h`
+//! is generated with `task header`.
+//! The option type is passed as  q=±1,  failures of the implied volatility functions are signalled
+//! by the values `IMPL_VOL_VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC` and
+//! `IMPL_VOL_VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM`.
+//!
+
+use crate::erf_cody::*;
+use crate::lets_be_rational::*;
+use core::slice;
+
+/// Returned by implied volatility functions when the price is below the intrinsic value.
+pub const IMPL_VOL_VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC: f64 = -1.7976931348623157e308;
+
+/// Returned by implied volatility functions when the price is at or above the maximum price.
+pub const IMPL_VOL_VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM: f64 = 1.7976931348623157e308;
+
+/// Returned by batch functions on success.
+pub const IMPL_VOL_OK: i32 = 0;
+
+/// Returned by batch functions when a pointer is null.
+pub const IMPL_VOL_NULL_POINTER: i32 = -1;
+
+/// Undiscounted Black price of a call (q=1) or put (q=-1).
+#[no_mangle]
+pub extern "C" fn impl_vol_black(f: f64, k: f64, sigma: f64, t: f64, q: f64) -> f64 {
+  black(f, k, sigma, t, q)
+}
+
+/// Normalised Black call price for  x = ln(F/K)  and  s = σ·√T.
+#[no_mangle]
+pub extern "C" fn impl_vol_normalised_black_call(x: f64, s: f64) -> f64 {
+  normalised_black_call(x, s)
+}
+
+/// Normalised Black price of a call (q=1) or put (q=-1).
+#[no_mangle]
+pub extern "C" fn impl_vol_normalised_black(x: f64, s: f64, q: f64) -> f64 {
+  normalised_black(x, s, q)
+}
+
+/// Derivative of the normalised Black price with respect to s.
+#[no_mangle]
+pub extern "C" fn impl_vol_normalised_vega(x: f64, s: f64) -> f64 {
+  normalised_vega(x, s)
+}
+
+/// Implied volatility from an undiscounted price.
+#[no_mangle]
+pub extern "C" fn impl_vol_implied_volatility_from_a_transformed_rational_guess(price: f64, f: f64, k: f64, t: f64, q: f64) -> f64 {
+  implied_volatility_from_a_transformed_rational_guess(price, f, k, t, q)
+}
+
+/// Implied volatility from an undiscounted price using at most `n` iterations.
+#[no_mangle]
+pub extern "C" fn impl_vol_implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(price: f64, f: f64, k: f64, t: f64, q: f64, n: u32) -> f64 {
+  implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(price, f, k, t, q, n as usize)
+}
+
+/// Implied total volatility  s = σ·√T  from a normalised price.
+#[no_mangle]
+pub extern "C" fn impl_vol_normalised_implied_volatility_from_a_transformed_rational_guess(beta: f64, x: f64, q: f64) -> f64 {
+  normalised_implied_volatility_from_a_transformed_rational_guess(beta, x, q)
+}
+
+/// Implied total volatility  s = σ·√T  from a normalised price using at most `n` iterations.
+#[no_mangle]
+pub extern "C" fn impl_vol_normalised_implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(beta: f64, x: f64, q: f64, n: u32) -> f64 {
+  normalised_implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(beta, x, q, n as usize)
+}
+
+/// Error function.
+#[no_mangle]
+pub extern "C" fn impl_vol_erf_cody(x: f64) -> f64 {
+  erf_cody(x)
+}
+
+/// Complementary error function.
+#[no_mangle]
+pub extern "C" fn impl_vol_erfc_cody(x: f64) -> f64 {
+  erfc_cody(x)
+}
+
+/// Scaled complementary error function  exp(x²)·erfc(x).
+#[no_mangle]
+pub extern "C" fn impl_vol_erfcx_cody(x: f64) -> f64 {
+  erfcx_cody(x)
+}
+
+/// Computes `n` implied volatilities from arrays of prices, forwards, strikes, expiries and option types.
+///
+/// Returns `IMPL_VOL_OK`, or `IMPL_VOL_NULL_POINTER` without writing any results when a pointer is null and `n > 0`.
+///
+/// # Safety
+///
+/// Each non-null pointer must be valid for `n` consecutive values, and `volatility` must not overlap the inputs.
+#[no_mangle]
+pub unsafe extern "C" fn impl_vol_implied_volatility_batch(price: *const f64, f: *const f64, k: *const f64, t: *const f64, q: *const f64, n: usize, volatility: *mut f64) -> i32 {
+  if n == 0 {
+    return IMPL_VOL_OK;
+  }
+  if price.is_null() || f.is_null() || k.is_null() || t.is_null() || q.is_null() || volatility.is_null() {
+    return IMPL_VOL_NULL_POINTER;
+  }
+  let (price, f, k, t, q) = (
+    slice::from_raw_parts(price, n),
+    slice::from_raw_parts(f, n),
+    slice::from_raw_parts(k, n),
+    slice::from_raw_parts(t, n),
+    slice::from_raw_parts(q, n),
+  );
+  for (i, volatility) in slice::from_raw_parts_mut(volatility, n).iter_mut().enumerate() {
+    *volatility = implied_volatility_from_a_transformed_rational_guess(price[i], f[i], k[i], t[i], q[i]);
+  }
+  IMPL_VOL_OK
+}
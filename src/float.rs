@@ -0,0 +1,299 @@
+//!
+//! Floating-point types supported by the pricing and implied volatility functions.
+//!
+//! The algorithms are written once, generic over [Float], and instantiated for `f32` and `f64`.
+//! Thresholds depending on the machine precision, e.g. the small-t expansion cutoff  τ = 2·ε^(1/16),
+//! are associated constants, so that each type uses values derived from its own epsilon.
+//!
+//! The trait is sealed: the coefficients of Cody's error functions and of the rational guess are those of double precision,
+//! and the thresholds are validated for `f32` and `f64` only.
+//!
+
+use crate::definitions::*;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+mod sealed {
+  pub trait Sealed {}
+  impl Sealed for f32 {}
+  impl Sealed for f64 {}
+}
+
+/// Floating-point type of the pricing and implied volatility functions, implemented for `f32` and `f64`.
+pub trait Float:
+  sealed::Sealed
+  + Copy
+  + PartialOrd
+  + Add<Output = Self>
+  + Sub<Output = Self>
+  + Mul<Output = Self>
+  + Div<Output = Self>
+  + Neg<Output = Self>
+  + AddAssign
+  + SubAssign
+  + MulAssign
+  + DivAssign
+{
+  const ZERO: Self;
+  const HALF: Self;
+  const ONE: Self;
+  const TWO: Self;
+  /// Machine epsilon  ε.
+  const EPSILON: Self;
+  /// Smallest positive normal value.
+  const MIN_POSITIVE: Self;
+  /// Largest finite value.
+  const MAX: Self;
+  const NAN: Self;
+  /// ε^(1/2)
+  const SQRT_EPSILON: Self;
+  /// ε^(1/4)
+  const FOURTH_ROOT_EPSILON: Self;
+  /// ε^(1/16)
+  const SIXTEENTH_ROOT_EPSILON: Self;
+  /// Square root of the smallest positive normal value.
+  const SQRT_MIN_POSITIVE: Self;
+  /// Square root of the largest finite value.
+  const SQRT_MAX: Self;
+  /// Threshold  η  of  h = x/s  below which the normalised Black call price is evaluated by its 17th order asymptotic expansion,
+  /// for  t = s/2  up to  τ+|h|-|η|  with  τ = 2·ε^(1/16).  The truncation error of  Φ(h+t)  is 1.64E-16 at  h+t = -10,  and
+  /// 4.6E-8 at  h+t = -6.5+τ = -5.76  in single precision, where  τ ≈ 0.74  is not negligible.
+  const ASYMPTOTIC_EXPANSION_ACCURACY_THRESHOLD: Self;
+  /// Threshold of  z  below which  Φ(z)  is evaluated by its asymptotic expansion. Its smallest term, which bounds the error,
+  /// is 1.64E-16 at  z = -10,  and 1.1E-8 at  z = -6.
+  const NORM_CDF_ASYMPTOTIC_EXPANSION_FIRST_THRESHOLD: Self;
+  /// Machine-dependent constants XINF, XNEG, XSMALL, XBIG, XHUGE and XMAX of Cody's error functions,
+  /// see the comments of http://www.netlib.org/specfun/erf.
+  const ERF_XINF: Self;
+  const ERF_XNEG: Self;
+  const ERF_XSMALL: Self;
+  const ERF_XBIG: Self;
+  const ERF_XHUGE: Self;
+  const ERF_XMAX: Self;
+
+  /// Converts a coefficient, rounding to the nearest value of this type.
+  fn from_f64(x: f64) -> Self;
+  fn abs(self) -> Self;
+  fn is_nan(self) -> bool;
+  fn exp(self) -> Self;
+  fn ln(self) -> Self;
+  fn sqrt(self) -> Self;
+  fn powf(self, n: Self) -> Self;
+  fn floor(self) -> Self;
+}
+
+impl Float for f64 {
+  const ZERO: f64 = 0.0;
+  const HALF: f64 = 0.5;
+  const ONE: f64 = 1.0;
+  const TWO: f64 = 2.0;
+  const EPSILON: f64 = DBL_EPSILON;
+  const MIN_POSITIVE: f64 = DBL_MIN;
+  const MAX: f64 = DBL_MAX;
+  const NAN: f64 = f64::NAN;
+  const SQRT_EPSILON: f64 = SQRT_DBL_EPSILON;
+  const FOURTH_ROOT_EPSILON: f64 = FOURTH_ROOT_DBL_EPSILON;
+  const SIXTEENTH_ROOT_EPSILON: f64 = SIXTEENTH_ROOT_DBL_EPSILON;
+  const SQRT_MIN_POSITIVE: f64 = SQRT_DBL_MIN;
+  const SQRT_MAX: f64 = SQRT_DBL_MAX;
+  const ASYMPTOTIC_EXPANSION_ACCURACY_THRESHOLD: f64 = -10.0;
+  const NORM_CDF_ASYMPTOTIC_EXPANSION_FIRST_THRESHOLD: f64 = -10.0;
+  const ERF_XINF: f64 = 1.79e308;
+  const ERF_XNEG: f64 = -26.628;
+  const ERF_XSMALL: f64 = 1.11e-16;
+  const ERF_XBIG: f64 = 26.543;
+  const ERF_XHUGE: f64 = 6.71e7;
+  const ERF_XMAX: f64 = 2.53e307;
+
+  #[inline(always)]
+  fn from_f64(x: f64) -> f64 {
+    x
+  }
+
+  #[inline(always)]
+  fn abs(self) -> f64 {
+    f64::abs(self)
+  }
+
+  #[inline(always)]
+  fn is_nan(self) -> bool {
+    f64::is_nan(self)
+  }
+
+  #[cfg(feature = "std")]
+  #[inline(always)]
+  fn exp(self) -> f64 {
+    f64::exp(self)
+  }
+
+  #[cfg(not(feature = "std"))]
+  #[inline(always)]
+  fn exp(self) -> f64 {
+    libm::exp(self)
+  }
+
+  #[cfg(feature = "std")]
+  #[inline(always)]
+  fn ln(self) -> f64 {
+    f64::ln(self)
+  }
+
+  #[cfg(not(feature = "std"))]
+  #[inline(always)]
+  fn ln(self) -> f64 {
+    libm::log(self)
+  }
+
+  #[cfg(feature = "std")]
+  #[inline(always)]
+  fn sqrt(self) -> f64 {
+    f64::sqrt(self)
+  }
+
+  #[cfg(not(feature = "std"))]
+  #[inline(always)]
+  fn sqrt(self) -> f64 {
+    libm::sqrt(self)
+  }
+
+  #[cfg(feature = "std")]
+  #[inline(always)]
+  fn powf(self, n: f64) -> f64 {
+    f64::powf(self, n)
+  }
+
+  #[cfg(not(feature = "std"))]
+  #[inline(always)]
+  fn powf(self, n: f64) -> f64 {
+    libm::pow(self, n)
+  }
+
+  #[cfg(feature = "std")]
+  #[inline(always)]
+  fn floor(self) -> f64 {
+    f64::floor(self)
+  }
+
+  #[cfg(not(feature = "std"))]
+  #[inline(always)]
+  fn floor(self) -> f64 {
+    libm::floor(self)
+  }
+}
+
+impl Float for f32 {
+  const ZERO: f32 = 0.0;
+  const HALF: f32 = 0.5;
+  const ONE: f32 = 1.0;
+  const TWO: f32 = 2.0;
+  const EPSILON: f32 = f32::EPSILON;
+  const MIN_POSITIVE: f32 = f32::MIN_POSITIVE;
+  const MAX: f32 = f32::MAX;
+  const NAN: f32 = f32::NAN;
+  const SQRT_EPSILON: f32 = 0.00034526698;
+  const FOURTH_ROOT_EPSILON: f32 = 0.01858136;
+  const SIXTEENTH_ROOT_EPSILON: f32 = 0.36920655;
+  const SQRT_MIN_POSITIVE: f32 = 1.0842022e-19;
+  const SQRT_MAX: f32 = 1.8446743e19;
+  const ASYMPTOTIC_EXPANSION_ACCURACY_THRESHOLD: f32 = -6.5;
+  const NORM_CDF_ASYMPTOTIC_EXPANSION_FIRST_THRESHOLD: f32 = -6.0;
+  const ERF_XINF: f32 = 3.40e38;
+  const ERF_XNEG: f32 = -9.382;
+  const ERF_XSMALL: f32 = 5.96e-8;
+  const ERF_XBIG: f32 = 9.194;
+  const ERF_XHUGE: f32 = 2.90e3;
+  const ERF_XMAX: f32 = 4.79e37;
+
+  #[inline(always)]
+  fn from_f64(x: f64) -> f32 {
+    x as f32
+  }
+
+  #[inline(always)]
+  fn abs(self) -> f32 {
+    f32::abs(self)
+  }
+
+  #[inline(always)]
+  fn is_nan(self) -> bool {
+    f32::is_nan(self)
+  }
+
+  #[cfg(feature = "std")]
+  #[inline(always)]
+  fn exp(self) -> f32 {
+    f32::exp(self)
+  }
+
+  #[cfg(not(feature = "std"))]
+  #[inline(always)]
+  fn exp(self) -> f32 {
+    libm::expf(self)
+  }
+
+  #[cfg(feature = "std")]
+  #[inline(always)]
+  fn ln(self) -> f32 {
+    f32::ln(self)
+  }
+
+  #[cfg(not(feature = "std"))]
+  #[inline(always)]
+  fn ln(self) -> f32 {
+    libm::logf(self)
+  }
+
+  #[cfg(feature = "std")]
+  #[inline(always)]
+  fn sqrt(self) -> f32 {
+    f32::sqrt(self)
+  }
+
+  #[cfg(not(feature = "std"))]
+  #[inline(always)]
+  fn sqrt(self) -> f32 {
+    libm::sqrtf(self)
+  }
+
+  #[cfg(feature = "std")]
+  #[inline(always)]
+  fn powf(self, n: f32) -> f32 {
+    f32::powf(self, n)
+  }
+
+  #[cfg(not(feature = "std"))]
+  #[inline(always)]
+  fn powf(self, n: f32) -> f32 {
+    libm::powf(self, n)
+  }
+
+  #[cfg(feature = "std")]
+  #[inline(always)]
+  fn floor(self) -> f32 {
+    f32::floor(self)
+  }
+
+  #[cfg(not(feature = "std"))]
+  #[inline(always)]
+  fn floor(self) -> f32 {
+    libm::floorf(self)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn check_roots<T: Float + core::fmt::Debug>() {
+    assert_eq!(T::EPSILON.sqrt(), T::SQRT_EPSILON);
+    assert_eq!(T::SQRT_EPSILON.sqrt(), T::FOURTH_ROOT_EPSILON);
+    assert_eq!(T::FOURTH_ROOT_EPSILON.sqrt().sqrt(), T::SIXTEENTH_ROOT_EPSILON);
+    assert_eq!(T::MIN_POSITIVE.sqrt(), T::SQRT_MIN_POSITIVE);
+    assert_eq!(T::MAX.sqrt(), T::SQRT_MAX);
+  }
+
+  #[test]
+  fn test_roots_of_epsilon() {
+    check_roots::<f64>();
+    check_roots::<f32>();
+  }
+}
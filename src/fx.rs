@@ -0,0 +1,143 @@
+//!
+//! Conversion between FX market quotes (ATM straddle, risk reversal and butterfly) and strike/volatility pairs.
+//!
+//! Butterflies are interpreted as smile strangles, i.e. for each wing delta
+//!
+//! ```text
+//!     σ_call  =  σ_atm + bf + rr/2
+//!     σ_put   =  σ_atm + bf - rr/2
+//! ```
+//!
+
+use crate::definitions::*;
+use crate::delta::*;
+use crate::lets_be_rational::*;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Conventions for the at-the-money strike.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AtmConvention {
+  /// Strike equals the forward.
+  Forward,
+  /// Strike at which the straddle has zero delta.
+  DeltaNeutralStraddle,
+}
+
+/// Risk reversal and butterfly quoted for one wing delta, e.g. 0.25 or 0.10.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FxWingQuote {
+  pub delta: f64,
+  pub risk_reversal: f64,
+  pub butterfly: f64,
+}
+
+/// Market quote for one tenor: ATM volatility and one (three-point smile) or two (five-point smile) wings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FxMarketQuote {
+  pub atm_volatility: f64,
+  pub wings: Vec<FxWingQuote>,
+}
+
+/// Identifies a point of the smile.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FxPillar {
+  Atm,
+  /// Call with the given (positive) delta.
+  Call(f64),
+  /// Put with the given (positive) absolute delta.
+  Put(f64),
+}
+
+impl FxPillar {
+  /// Returns the option type priced at this pillar, q=±1.
+  pub fn q(&self) -> f64 {
+    sel(matches!(self, FxPillar::Put(_)), -1.0, 1.0)
+  }
+}
+
+/// Single point of the smile with its undiscounted premium.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FxSmilePoint {
+  pub pillar: FxPillar,
+  pub strike: f64,
+  pub volatility: f64,
+  pub price: f64,
+}
+
+/// Returns the at-the-money strike.
+pub fn atm_strike(f: f64, sigma: f64, t: f64, delta_convention: DeltaConvention, atm_convention: AtmConvention) -> f64 {
+  match atm_convention {
+    AtmConvention::Forward => f,
+    AtmConvention::DeltaNeutralStraddle => {
+      let half_variance = 0.5 * sigma * sigma * t;
+      f * exp(sel(delta_convention.is_premium_adjusted(), -half_variance, half_variance))
+    }
+  }
+}
+
+/// Builds the three- or five-point smile, sorted by strike, from the market quote of one tenor.
+/// Returns `None` when the strike of a wing does not exist for its delta, see [strike_from_delta].
+pub fn fx_smile_from_market_quote(
+  quote: &FxMarketQuote,
+  f: f64,
+  t: f64,
+  df_foreign: f64,
+  delta_convention: DeltaConvention,
+  atm_convention: AtmConvention,
+) -> Option<Vec<FxSmilePoint>> {
+  let point = |pillar: FxPillar, strike: f64, volatility: f64| FxSmilePoint {
+    pillar,
+    strike,
+    volatility,
+    price: black(f, strike, volatility, t, pillar.q()),
+  };
+  let mut points = Vec::with_capacity(1 + 2 * quote.wings.len());
+  points.push(point(
+    FxPillar::Atm,
+    atm_strike(f, quote.atm_volatility, t, delta_convention, atm_convention),
+    quote.atm_volatility,
+  ));
+  for wing in &quote.wings {
+    let call_volatility = quote.atm_volatility + wing.butterfly + 0.5 * wing.risk_reversal;
+    let put_volatility = quote.atm_volatility + wing.butterfly - 0.5 * wing.risk_reversal;
+    let call_strike = strike_from_delta(wing.delta, f, call_volatility, t, 1.0, df_foreign, delta_convention);
+    let put_strike = strike_from_delta(-wing.delta, f, put_volatility, t, -1.0, df_foreign, delta_convention);
+    if call_strike.is_nan() || put_strike.is_nan() {
+      return None;
+    }
+    points.push(point(FxPillar::Call(wing.delta), call_strike, call_volatility));
+    points.push(point(FxPillar::Put(wing.delta), put_strike, put_volatility));
+  }
+  points.sort_by(|a, b| a.strike.total_cmp(&b.strike));
+  Some(points)
+}
+
+/// Builds a smile point from an undiscounted premium using the implied volatility solver.
+pub fn fx_smile_point_from_price(pillar: FxPillar, price: f64, f: f64, k: f64, t: f64) -> FxSmilePoint {
+  FxSmilePoint {
+    pillar,
+    strike: k,
+    volatility: implied_volatility_from_a_transformed_rational_guess(price, f, k, t, pillar.q()),
+    price,
+  }
+}
+
+/// Recovers the market quote from smile points.
+/// Returns `None` when the ATM point is missing or a call wing has no matching put wing.
+pub fn fx_market_quote_from_smile(points: &[FxSmilePoint]) -> Option<FxMarketQuote> {
+  let atm_volatility = points.iter().find(|point| point.pillar == FxPillar::Atm)?.volatility;
+  let mut wings = vec![];
+  for call in points {
+    if let FxPillar::Call(delta) = call.pillar {
+      let put = points.iter().find(|point| point.pillar == FxPillar::Put(delta))?;
+      wings.push(FxWingQuote {
+        delta,
+        risk_reversal: call.volatility - put.volatility,
+        butterfly: 0.5 * (call.volatility + put.volatility) - atm_volatility,
+      });
+    }
+  }
+  wings.sort_by(|a, b| b.delta.total_cmp(&a.delta));
+  Some(FxMarketQuote { atm_volatility, wings })
+}
@@ -0,0 +1,57 @@
+//!
+//! Sensitivities of the undiscounted Black price.
+//!
+//! With  s = σ·√T,  d1 = ln(F/K)/s + s/2  and  d2 = d1 - s,
+//!
+//! ```text
+//!     delta  =  ∂V/∂F    =  q·Φ(q·d1)
+//!     gamma  =  ∂²V/∂F²  =  φ(d1)/(F·s)
+//!     vega   =  ∂V/∂σ    =  F·φ(d1)·√T
+//!     theta  = -∂V/∂T    = -F·φ(d1)·σ/(2·√T)
+//!     vanna  =  ∂²V/∂F∂σ = -φ(d1)·d2/σ
+//!     volga  =  ∂²V/∂σ²  =  vega·d1·d2/σ
+//! ```
+//!
+
+use crate::definitions::*;
+use crate::lets_be_rational::*;
+use crate::normal_distribution::*;
+
+/// Price and sensitivities of the undiscounted Black price.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BlackGreeks {
+  pub price: f64,
+  pub delta: f64,
+  pub gamma: f64,
+  pub vega: f64,
+  pub theta: f64,
+  pub vanna: f64,
+  pub volga: f64,
+}
+
+/// Returns the sensitivity of the undiscounted Black price to the volatility.
+pub fn black_vega(f: f64, k: f64, sigma: f64, t: f64) -> f64 {
+  let sqrt_t = sqrt(t);
+  // F·φ(d1) = √(F·K)·φ(x/s)·exp(-s²/8) evaluated without cancellation in the exponent.
+  sqrt(f) * sqrt(k) * normalised_vega(log(f / k), sigma * sqrt_t) * sqrt_t
+}
+
+/// Returns the price and sensitivities of the undiscounted Black price.
+pub fn black_greeks(f: f64, k: f64, sigma: f64, t: f64, q: f64 /* q=±1 */) -> BlackGreeks {
+  let q = sel(q < 0.0, -1.0, 1.0);
+  let sqrt_t = sqrt(t);
+  let s = sigma * sqrt_t;
+  let d1 = log(f / k) / s + 0.5 * s;
+  let d2 = d1 - s;
+  let vega = black_vega(f, k, sigma, t);
+  let f_phi_d1 = vega / sqrt_t;
+  BlackGreeks {
+    price: black(f, k, sigma, t, q),
+    delta: q * norm_cdf(q * d1),
+    gamma: f_phi_d1 / (f * f * s),
+    vega,
+    theta: -0.5 * f_phi_d1 * sigma / sqrt_t,
+    vanna: -f_phi_d1 / f * d2 / sigma,
+    volga: vega * d1 * d2 / sigma,
+  }
+}
@@ -0,0 +1,132 @@
+//!
+//! Safeguarded Householder iteration for the positive root of a monotonic objective function.
+//!
+//! See www.en.wikipedia.org/wiki/Householder%27s_method for a detailed explanation of the third order Householder iteration.
+//!
+//! Given the objective function g(s) whose root s such that 0 = g(s) we seek, iterate
+//!
+//! ```text
+//!     s_n+1  =  s_n  -  (g/g') · [ 1 - (g''/g')·(g/g') ] / [ 1 - (g/g')·( (g''/g') - (g'''/g')·(g/g')/6 ) ]
+//! ```
+//!
+//! Denoting  newton:=-(g/g'), halley:=(g''/g'), and hh3:=(g'''/g'), this reads
+//!
+//! ```text
+//!     s_n+1  =  s_n  +  newton · [ 1 + halley·newton/2 ] / [ 1 + newton·( halley + hh3·newton/6 ) ]
+//! ```
+//!
+//! Every evaluation of the objective function tightens a bracket of the root. When a step leaves the bracket, or the steps
+//! keep changing direction, the iteration switches to binary nesting.
+//!
+
+use crate::definitions::*;
+use crate::float::Float;
+
+/// Ratios of the derivatives of the objective function at the evaluation point, see [safeguarded_householder_root].
+///
+/// Where g''/g' and g'''/g' over- or underflow, they may be given relative to a `scale` of the root, such as  s  itself.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HouseholderRatios<T> {
+  /// Newton step  -g/g'.
+  pub newton: T,
+  /// scale·g''/g'.
+  pub halley: T,
+  /// scale²·g'''/g'.
+  pub hh3: T,
+  /// Scale of `halley` and `hh3`, one for the plain ratios.
+  pub scale: T,
+}
+
+impl<T: Float> HouseholderRatios<T> {
+  /// Returns the plain ratios of the value `g` and the first three derivatives `g1`, `g2` and `g3` of the objective function.
+  pub fn from_derivatives(g: T, g1: T, g2: T, g3: T) -> HouseholderRatios<T> {
+    HouseholderRatios {
+      newton: -g / g1,
+      halley: g2 / g1,
+      hh3: g3 / g1,
+      scale: T::ONE,
+    }
+  }
+}
+
+/// Evaluation of the objective function at a point, see [safeguarded_householder_root].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HouseholderEvaluation<T> {
+  /// Value of the objective function, or of any function of the same sign, when the objective function is increasing.
+  /// For decreasing objective functions, its negative, which leaves the ratios unchanged.
+  pub g: T,
+  /// Ratios of the derivatives, or `None` where they are not available, e.g. by numerical underflow, to bisect the bracket instead.
+  pub ratios: Option<HouseholderRatios<T>>,
+}
+
+impl<T: Float> HouseholderEvaluation<T> {
+  /// Returns the evaluation of an increasing objective function from its value `g` and first three derivatives `g1`, `g2` and `g3`.
+  pub fn from_derivatives(g: T, g1: T, g2: T, g3: T) -> HouseholderEvaluation<T> {
+    HouseholderEvaluation {
+      g,
+      ratios: Some(HouseholderRatios::from_derivatives(g, g1, g2, g3)),
+    }
+  }
+}
+
+/// Correction of the Newton step of the Householder method of the given order: 4 for Householder(3), 3 for Halley and 2 for Newton.
+fn householder_factor<T: Float>(order: usize, newton: T, halley: T, hh3: T) -> T {
+  if order > 3 {
+    (T::ONE + T::HALF * halley * newton) / (T::ONE + newton * (halley + hh3 * newton / T::from_f64(6.0)))
+  } else if order > 2 {
+    T::ONE / (T::ONE + T::HALF * halley * newton)
+  } else {
+    T::ONE
+  }
+}
+
+/// Positive root of the monotonic `objective` function within the bracket (`s_left`, `s_right`), starting from `s`, by at most `n`
+/// iterations of the Householder method of the given `order`: 4 for Householder(3), 3 for Halley and 2 for Newton.
+///
+/// Steps never reduce  s  by more than half. The iteration stops when a step is within the relative precision of  s,  or the
+/// bracket has shrunk to it. Returns the root and the number of iterations.
+pub fn safeguarded_householder_root<T: Float>(
+  mut objective: impl FnMut(T) -> HouseholderEvaluation<T>,
+  mut s: T,
+  mut s_left: T,
+  mut s_right: T,
+  order: usize,
+  n: usize,
+) -> (T, usize) {
+  let mut iterations = 0_usize;
+  let mut direction_reversal_count = 0_usize;
+  let mut ds = -T::MAX;
+  let mut ds_previous = T::ZERO;
+  while iterations < n && fabs(ds) > T::EPSILON * s {
+    if ds * ds_previous < T::ZERO {
+      direction_reversal_count += 1;
+    }
+    if iterations > 0 && (3 == direction_reversal_count || !(s > s_left && s < s_right)) {
+      // If looping inefficiently, or the forecast step takes us outside the bracket, or onto its edges, switch to binary nesting.
+      // NOTE that this can only really happen for very extreme values of |x|, such as |x| = |ln(F/K)| > 500, in the implied volatility solver.
+      s = T::HALF * (s_left + s_right);
+      if s_right - s_left <= T::EPSILON * s {
+        break;
+      };
+      direction_reversal_count = 0;
+      ds = T::ZERO;
+    }
+    ds_previous = ds;
+    let evaluation = objective(s);
+    if evaluation.g > T::ZERO && s < s_right {
+      // Tighten the bracket if applicable.
+      s_right = s;
+    } else if evaluation.g < T::ZERO && s > s_left {
+      s_left = s;
+    }
+    ds = match evaluation.ratios {
+      Some(ratios) => ratios.newton * householder_factor(order, ratios.newton / ratios.scale, ratios.halley, ratios.hh3),
+      // Switch to binary nesting for this iteration.
+      None => T::HALF * (s_left + s_right) - s,
+    };
+    ds = max(-T::HALF * s, ds);
+    s += ds;
+    iterations += 1;
+  }
+  (s, iterations)
+}
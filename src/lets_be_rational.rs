@@ -1,8 +1,10 @@
 use crate::definitions::*;
 use crate::erf_cody::*;
+use crate::float::Float;
+use crate::householder::*;
 use crate::normal_distribution::*;
 use crate::rational_cubic::*;
-use std::sync::atomic::{AtomicUsize, Ordering};
+use core::sync::atomic::{AtomicUsize, Ordering};
 
 #[allow(clippy::excessive_precision)]
 const TWO_PI: f64 = 6.283185307179586476925286766559005768394338798750;
@@ -14,7 +16,7 @@ const SQRT_PI_OVER_TWO: f64 = 1.253314137315500251207882642405522626503493370305
 const SQRT_ONE_OVER_THREE: f64 = 0.577350269189625764509148780501957455647601751270;
 #[allow(clippy::excessive_precision)]
 const TWO_PI_OVER_SQRT_TWENTY_SEVEN: f64 = 1.209199576156145233729385505094770488189377498728; // 2*pi/sqrt(27)
-const PI_OVER_SIX: f64 = std::f64::consts::FRAC_PI_6;
+const PI_OVER_SIX: f64 = core::f64::consts::FRAC_PI_6;
 
 /// Set this to 0 if you want positive results for (positive) denormalised inputs, else to DBL_MIN.
 /// Note that you cannot achieve full machine accuracy from denormalised inputs!
@@ -25,7 +27,7 @@ const DENORMALISATION_CUTOFF: f64 = 0.0;
 /// and binary nesting due to roundoff truncation.
 static IMPLIED_VOLATILITY_MAXIMUM_ITERATIONS: AtomicUsize = AtomicUsize::new(2);
 
-fn get_implied_volatility_maximum_iterations() -> usize {
+pub(crate) fn get_implied_volatility_maximum_iterations() -> usize {
   IMPLIED_VOLATILITY_MAXIMUM_ITERATIONS.load(Ordering::Relaxed)
 }
 
@@ -38,11 +40,11 @@ fn get_implied_volatility_output_type() -> usize {
 }
 
 #[cfg(feature = "ENABLE_SWITCHING_THE_OUTPUT_TO_ITERATION_COUNT")]
-fn implied_volatility_output(count: usize, volatility: f64) -> f64 {
-  sel(get_implied_volatility_output_type() > 0, count as f64, volatility)
+pub(crate) fn implied_volatility_output<T: Float>(count: usize, volatility: T) -> T {
+  sel(get_implied_volatility_output_type() > 0, T::from_f64(count as f64), volatility)
 }
 #[cfg(not(feature = "ENABLE_SWITCHING_THE_OUTPUT_TO_ITERATION_COUNT"))]
-fn implied_volatility_output(count: usize, volatility: f64) -> f64 {
+pub(crate) fn implied_volatility_output<T: Float>(_count: usize, volatility: T) -> T {
   volatility
 }
 
@@ -59,18 +61,16 @@ fn get_implied_volatility_householder_method_order() -> usize {
   IMPLIED_VOLATILITY_HOUSEHOLDER_METHOD_ORDER.load(Ordering::Relaxed)
 }
 
+/// Order of the Householder method of the implied volatility solver.
 #[cfg(feature = "ENABLE_CHANGING_THE_HOUSEHOLDER_METHOD_ORDER")]
-fn householder_factor(newton: f64, halley: f64, hh3: f64) -> f64 {
-  sel(
-    get_implied_volatility_householder_method_order() > 3,
-    (1.0 + 0.5 * halley * newton) / (1.0 + newton * (halley + hh3 * newton / 6.0)),
-    sel(get_implied_volatility_householder_method_order() > 2, 1.0 / (1.0 + 0.5 * halley * newton), 1.0),
-  )
+pub(crate) fn implied_volatility_householder_method_order() -> usize {
+  get_implied_volatility_householder_method_order()
 }
 
+/// Order of the Householder method of the implied volatility solver, i.e. Householder(3).
 #[cfg(not(feature = "ENABLE_CHANGING_THE_HOUSEHOLDER_METHOD_ORDER"))]
-fn householder_factor(newton: f64, halley: f64, hh3: f64) -> f64 {
-  (1 + 0.5 * halley * newton) / (1 + newton * (halley + hh3 * newton / 6))
+pub(crate) fn implied_volatility_householder_method_order() -> usize {
+  4
 }
 
 ///```text
@@ -96,15 +96,28 @@ fn householder_factor(newton: f64, halley: f64, hh3: f64) -> f64 {
 ///
 /// with r := (h+t)·(h-t) and give an expansion for A(h,t) in q:=(h/r)² expressed in terms of e:=(t/h)² .
 /// ```
+fn asymptotic_expansion_of_normalised_black_call<T: Float>(h: T, t: T) -> T {
+  let r = (h + t) * (h - t);
+  let asymptotic_expansion_sum = asymptotic_expansion_sum(h, t);
+  let b = T::from_f64(ONE_OVER_SQRT_TWO_PI) * exp(-T::HALF * (h * h + t * t)) * (t / r) * asymptotic_expansion_sum;
+  fabs(max(b, T::ZERO))
+}
+
+/// Logarithm of [asymptotic_expansion_of_normalised_black_call], without underflow for large negative (t-|h|).
+fn ln_asymptotic_expansion_of_normalised_black_call<T: Float>(h: T, t: T) -> T {
+  log(T::from_f64(ONE_OVER_SQRT_TWO_PI)) - T::HALF * (h * h + t * t) + log(t) - log(-(h + t)) - log(t - h) + log(asymptotic_expansion_sum(h, t))
+}
+
+/// The expansion of A(h,t) of [asymptotic_expansion_of_normalised_black_call].
 #[rustfmt::skip]
-fn asymptotic_expansion_of_normalised_black_call(h: f64, t: f64) -> f64 {
+fn asymptotic_expansion_sum<T: Float>(h: T, t: T) -> T {
+  let c = T::from_f64;
   let e = (t / h) * (t / h);
   let r = (h + t) * (h - t);
   let q = (h / r) * (h / r);
-  // 17th order asymptotic expansion of A(h,t) in q, sufficient for Φ(h) [and thus y(h)] to have relative accuracy of 1.64E-16 for h <= η  with  η:=-10.
-  let asymptotic_expansion_sum = 2.0+q*(-6.0E0-2.0*e+3.0*q*(1.0E1+e*(2.0E1+2.0*e)+5.0*q*(-1.4E1+e*(-7.0E1+e*(-4.2E1-2.0*e))+7.0*q*(1.8E1+e*(1.68E2+e*(2.52E2+e*(7.2E1+2.0*e)))+9.0*q*(-2.2E1+e*(-3.3E2+e*(-9.24E2+e*(-6.6E2+e*(-1.1E2-2.0*e))))+1.1E1*q*(2.6E1+e*(5.72E2+e*(2.574E3+e*(3.432E3+e*(1.43E3+e*(1.56E2+2.0*e)))))+1.3E1*q*(-3.0E1+e*(-9.1E2+e*(-6.006E3+e*(-1.287E4+e*(-1.001E4+e*(-2.73E3+e*(-2.1E2-2.0*e))))))+1.5E1*q*(3.4E1+e*(1.36E3+e*(1.2376E4+e*(3.8896E4+e*(4.862E4+e*(2.4752E4+e*(4.76E3+e*(2.72E2+2.0*e)))))))+1.7E1*q*(-3.8E1+e*(-1.938E3+e*(-2.3256E4+e*(-1.00776E5+e*(-1.84756E5+e*(-1.51164E5+e*(-5.4264E4+e*(-7.752E3+e*(-3.42E2-2.0*e))))))))+1.9E1*q*(4.2E1+e*(2.66E3+e*(4.0698E4+e*(2.3256E5+e*(5.8786E5+e*(7.05432E5+e*(4.0698E5+e*(1.08528E5+e*(1.197E4+e*(4.2E2+2.0*e)))))))))+2.1E1*q*(-4.6E1+e*(-3.542E3+e*(-6.7298E4+e*(-4.90314E5+e*(-1.63438E6+e*(-2.704156E6+e*(-2.288132E6+e*(-9.80628E5+e*(-2.01894E5+e*(-1.771E4+e*(-5.06E2-2.0*e))))))))))+2.3E1*q*(5.0E1+e*(4.6E3+e*(1.0626E5+e*(9.614E5+e*(4.08595E6+e*(8.9148E6+e*(1.04006E7+e*(6.53752E6+e*(2.16315E6+e*(3.542E5+e*(2.53E4+e*(6.0E2+2.0*e)))))))))))+2.5E1*q*(-5.4E1+e*(-5.85E3+e*(-1.6146E5+e*(-1.77606E6+e*(-9.37365E6+e*(-2.607579E7+e*(-4.01166E7+e*(-3.476772E7+e*(-1.687257E7+e*(-4.44015E6+e*(-5.9202E5+e*(-3.51E4+e*(-7.02E2-2.0*e))))))))))))+2.7E1*q*(5.8E1+e*(7.308E3+e*(2.3751E5+e*(3.12156E6+e*(2.003001E7+e*(6.919458E7+e*(1.3572783E8+e*(1.5511752E8+e*(1.0379187E8+e*(4.006002E7+e*(8.58429E6+e*(9.5004E5+e*(4.7502E4+e*(8.12E2+2.0*e)))))))))))))+2.9E1*q*(-6.2E1+e*(-8.99E3+e*(-3.39822E5+e*(-5.25915E6+e*(-4.032015E7+e*(-1.6934463E8+e*(-4.1250615E8+e*(-6.0108039E8+e*(-5.3036505E8+e*(-2.8224105E8+e*(-8.870433E7+e*(-1.577745E7+e*(-1.472562E6+e*(-6.293E4+e*(-9.3E2-2.0*e))))))))))))))+3.1E1*q*(6.6E1+e*(1.0912E4+e*(4.74672E5+e*(8.544096E6+e*(7.71342E7+e*(3.8707344E8+e*(1.14633288E9+e*(2.07431664E9+e*(2.33360622E9+e*(1.6376184E9+e*(7.0963464E8+e*(1.8512208E8+e*(2.7768312E7+e*(2.215136E6+e*(8.184E4+e*(1.056E3+2.0*e)))))))))))))))+3.3E1*(-7.0E1+e*(-1.309E4+e*(-6.49264E5+e*(-1.344904E7+e*(-1.4121492E8+e*(-8.344518E8+e*(-2.9526756E9+e*(-6.49588632E9+e*(-9.0751353E9+e*(-8.1198579E9+e*(-4.6399188E9+e*(-1.6689036E9+e*(-3.67158792E8+e*(-4.707164E7+e*(-3.24632E6+e*(-1.0472E5+e*(-1.19E3-2.0*e)))))))))))))))))*q))))))))))))))));
-  let b = ONE_OVER_SQRT_TWO_PI * exp(-0.5 * (h * h + t * t)) * (t / r) * asymptotic_expansion_sum;
-  fabs(max(b, 0.0))
+  // 17th order asymptotic expansion of A(h,t) in q, sufficient for Φ(h) [and thus y(h)] to have relative accuracy of 1.64E-16 for h <= η  with  η:=-10,
+  // and of 4.6E-8 in single precision, see Float::ASYMPTOTIC_EXPANSION_ACCURACY_THRESHOLD.
+  c(2.0)+q*(-c(6.0E0)-c(2.0)*e+c(3.0)*q*(c(1.0E1)+e*(c(2.0E1)+c(2.0)*e)+c(5.0)*q*(-c(1.4E1)+e*(-c(7.0E1)+e*(-c(4.2E1)-c(2.0)*e))+c(7.0)*q*(c(1.8E1)+e*(c(1.68E2)+e*(c(2.52E2)+e*(c(7.2E1)+c(2.0)*e)))+c(9.0)*q*(-c(2.2E1)+e*(-c(3.3E2)+e*(-c(9.24E2)+e*(-c(6.6E2)+e*(-c(1.1E2)-c(2.0)*e))))+c(1.1E1)*q*(c(2.6E1)+e*(c(5.72E2)+e*(c(2.574E3)+e*(c(3.432E3)+e*(c(1.43E3)+e*(c(1.56E2)+c(2.0)*e)))))+c(1.3E1)*q*(-c(3.0E1)+e*(-c(9.1E2)+e*(-c(6.006E3)+e*(-c(1.287E4)+e*(-c(1.001E4)+e*(-c(2.73E3)+e*(-c(2.1E2)-c(2.0)*e))))))+c(1.5E1)*q*(c(3.4E1)+e*(c(1.36E3)+e*(c(1.2376E4)+e*(c(3.8896E4)+e*(c(4.862E4)+e*(c(2.4752E4)+e*(c(4.76E3)+e*(c(2.72E2)+c(2.0)*e)))))))+c(1.7E1)*q*(-c(3.8E1)+e*(-c(1.938E3)+e*(-c(2.3256E4)+e*(-c(1.00776E5)+e*(-c(1.84756E5)+e*(-c(1.51164E5)+e*(-c(5.4264E4)+e*(-c(7.752E3)+e*(-c(3.42E2)-c(2.0)*e))))))))+c(1.9E1)*q*(c(4.2E1)+e*(c(2.66E3)+e*(c(4.0698E4)+e*(c(2.3256E5)+e*(c(5.8786E5)+e*(c(7.05432E5)+e*(c(4.0698E5)+e*(c(1.08528E5)+e*(c(1.197E4)+e*(c(4.2E2)+c(2.0)*e)))))))))+c(2.1E1)*q*(-c(4.6E1)+e*(-c(3.542E3)+e*(-c(6.7298E4)+e*(-c(4.90314E5)+e*(-c(1.63438E6)+e*(-c(2.704156E6)+e*(-c(2.288132E6)+e*(-c(9.80628E5)+e*(-c(2.01894E5)+e*(-c(1.771E4)+e*(-c(5.06E2)-c(2.0)*e))))))))))+c(2.3E1)*q*(c(5.0E1)+e*(c(4.6E3)+e*(c(1.0626E5)+e*(c(9.614E5)+e*(c(4.08595E6)+e*(c(8.9148E6)+e*(c(1.04006E7)+e*(c(6.53752E6)+e*(c(2.16315E6)+e*(c(3.542E5)+e*(c(2.53E4)+e*(c(6.0E2)+c(2.0)*e)))))))))))+c(2.5E1)*q*(-c(5.4E1)+e*(-c(5.85E3)+e*(-c(1.6146E5)+e*(-c(1.77606E6)+e*(-c(9.37365E6)+e*(-c(2.607579E7)+e*(-c(4.01166E7)+e*(-c(3.476772E7)+e*(-c(1.687257E7)+e*(-c(4.44015E6)+e*(-c(5.9202E5)+e*(-c(3.51E4)+e*(-c(7.02E2)-c(2.0)*e))))))))))))+c(2.7E1)*q*(c(5.8E1)+e*(c(7.308E3)+e*(c(2.3751E5)+e*(c(3.12156E6)+e*(c(2.003001E7)+e*(c(6.919458E7)+e*(c(1.3572783E8)+e*(c(1.5511752E8)+e*(c(1.0379187E8)+e*(c(4.006002E7)+e*(c(8.58429E6)+e*(c(9.5004E5)+e*(c(4.7502E4)+e*(c(8.12E2)+c(2.0)*e)))))))))))))+c(2.9E1)*q*(-c(6.2E1)+e*(-c(8.99E3)+e*(-c(3.39822E5)+e*(-c(5.25915E6)+e*(-c(4.032015E7)+e*(-c(1.6934463E8)+e*(-c(4.1250615E8)+e*(-c(6.0108039E8)+e*(-c(5.3036505E8)+e*(-c(2.8224105E8)+e*(-c(8.870433E7)+e*(-c(1.577745E7)+e*(-c(1.472562E6)+e*(-c(6.293E4)+e*(-c(9.3E2)-c(2.0)*e))))))))))))))+c(3.1E1)*q*(c(6.6E1)+e*(c(1.0912E4)+e*(c(4.74672E5)+e*(c(8.544096E6)+e*(c(7.71342E7)+e*(c(3.8707344E8)+e*(c(1.14633288E9)+e*(c(2.07431664E9)+e*(c(2.33360622E9)+e*(c(1.6376184E9)+e*(c(7.0963464E8)+e*(c(1.8512208E8)+e*(c(2.7768312E7)+e*(c(2.215136E6)+e*(c(8.184E4)+e*(c(1.056E3)+c(2.0)*e)))))))))))))))+c(3.3E1)*(-c(7.0E1)+e*(-c(1.309E4)+e*(-c(6.49264E5)+e*(-c(1.344904E7)+e*(-c(1.4121492E8)+e*(-c(8.344518E8)+e*(-c(2.9526756E9)+e*(-c(6.49588632E9)+e*(-c(9.0751353E9)+e*(-c(8.1198579E9)+e*(-c(4.6399188E9)+e*(-c(1.6689036E9)+e*(-c(3.67158792E8)+e*(-c(4.707164E7)+e*(-c(3.24632E6)+e*(-c(1.0472E5)+e*(-c(1.19E3)-c(2.0)*e)))))))))))))))))*q))))))))))))))))
 }
 
 ///```text
@@ -122,40 +135,53 @@ fn asymptotic_expansion_of_normalised_black_call(h: f64, t: f64) -> f64 {
 /// Theoretically accurate to (better than) precision  ε = 2.23E-16  when  h<=0  and  t < τ  with  τ := 2·ε^(1/16) ≈ 0.21.
 /// The main bottleneck for precision is the coefficient a:=1+h·Y(h) when |h|>1 .
 /// ```
+fn small_t_expansion_of_normalised_black_call<T: Float>(h: T, t: T) -> T {
+  let expansion = T::TWO * t * small_t_expansion_over_two_t(h, t);
+  let b = T::from_f64(ONE_OVER_SQRT_TWO_PI) * exp(-T::HALF * (h * h + t * t)) * expansion;
+  fabs(max(b, T::ZERO))
+}
+
+/// Logarithm of [small_t_expansion_of_normalised_black_call], without underflow for tiny t.
+fn ln_small_t_expansion_of_normalised_black_call<T: Float>(h: T, t: T) -> T {
+  log(T::from_f64(ONE_OVER_SQRT_TWO_PI)) - T::HALF * (h * h + t * t) + log(T::TWO * t) + log(small_t_expansion_over_two_t(h, t))
+}
+
+/// The expansion of Y(h+t)-Y(h-t) of [small_t_expansion_of_normalised_black_call], divided by 2t.
 #[rustfmt::skip]
-fn small_t_expansion_of_normalised_black_call(h: f64, t: f64) -> f64 {
+fn small_t_expansion_over_two_t<T: Float>(h: T, t: T) -> T {
+  let c = T::from_f64;
   // Y(h) := Φ(h)/φ(h) = √(π/2)·erfcx(-h/√2)
   // a := 1+h·Y(h)  --- Note that due to h<0, and h·Y(h) -> -1 (from above) as h -> -∞, we also have that a>0 and a -> 0 as h -> -∞
   // w := t² , h2 := h²
-  let a = 1.0 + h * (0.5 * SQRT_TWO_PI) * erfcx_cody(-ONE_OVER_SQRT_TWO * h);
+  let a = T::ONE + h * (T::HALF * T::from_f64(SQRT_TWO_PI)) * erfcx_cody(-T::from_f64(ONE_OVER_SQRT_TWO) * h);
   let w = t * t;
   let h2 = h * h;
-  let expansion = 2.0*t*(a+w*((-1.0+3.0*a+a*h2)/6.0+w*((-7.0+15.0*a+h2*(-1.0+10.0*a+a*h2))/120.0+w*((-57.0+105.0*a+h2*(-18.0+105.0*a+h2*(-1.0+21.0*a+a*h2)))/5040.0+w*((-561.0+945.0*a+h2*(-285.0+1260.0*a+h2*(-33.0+378.0*a+h2*(-1.0+36.0*a+a*h2))))/362880.0+w*((-6555.0+10395.0*a+h2*(-4680.0+17325.0*a+h2*(-840.0+6930.0*a+h2*(-52.0+990.0*a+h2*(-1.0+55.0*a+a*h2)))))/39916800.0+((-89055.0+135135.0*a+h2*(-82845.0+270270.0*a+h2*(-20370.0+135135.0*a+h2*(-1926.0+25740.0*a+h2*(-75.0+2145.0*a+h2*(-1.0+78.0*a+a*h2))))))*w)/6227020800.0))))));
-  let b = ONE_OVER_SQRT_TWO_PI * exp(-0.5 * (h * h + t * t)) * expansion;
-  fabs(max(b, 0.0))
+  a+w*((-c(1.0)+c(3.0)*a+a*h2)/c(6.0)+w*((-c(7.0)+c(15.0)*a+h2*(-c(1.0)+c(10.0)*a+a*h2))/c(120.0)+w*((-c(57.0)+c(105.0)*a+h2*(-c(18.0)+c(105.0)*a+h2*(-c(1.0)+c(21.0)*a+a*h2)))/c(5040.0)+w*((-c(561.0)+c(945.0)*a+h2*(-c(285.0)+c(1260.0)*a+h2*(-c(33.0)+c(378.0)*a+h2*(-c(1.0)+c(36.0)*a+a*h2))))/c(362880.0)+w*((-c(6555.0)+c(10395.0)*a+h2*(-c(4680.0)+c(17325.0)*a+h2*(-c(840.0)+c(6930.0)*a+h2*(-c(52.0)+c(990.0)*a+h2*(-c(1.0)+c(55.0)*a+a*h2)))))/c(39916800.0)+((-c(89055.0)+c(135135.0)*a+h2*(-c(82845.0)+c(270270.0)*a+h2*(-c(20370.0)+c(135135.0)*a+h2*(-c(1926.0)+c(25740.0)*a+h2*(-c(75.0)+c(2145.0)*a+h2*(-c(1.0)+c(78.0)*a+a*h2))))))*w)/c(6227020800.0))))))
 }
 
 ///
-fn normalised_intrinsic(x: f64, q: f64 /* q=±1 */) -> f64 {
-  if q * x <= 0.0 {
-    return 0.0;
+fn normalised_intrinsic<T: Float>(x: T, q: T /* q=±1 */) -> T {
+  if q * x <= T::ZERO {
+    return T::ZERO;
   }
   let x2 = x * x;
   // The factor 98 is computed from last coefficient: √√92897280 = 98.1749
-  if x2 < 98.0 * *FOURTH_ROOT_DBL_EPSILON {
+  if x2 < T::from_f64(98.0) * T::FOURTH_ROOT_EPSILON {
     return fabs(max(
-      sel(q < 0.0, -1.0, 1.0) * x * (1.0 + x2 * ((1.0 / 24.0) + x2 * ((1.0 / 1920.0) + x2 * ((1.0 / 322560.0) + (1.0 / 92897280.0) * x2)))),
-      0.0,
+      sel(q < T::ZERO, -T::ONE, T::ONE)
+        * x
+        * (T::ONE + x2 * (T::from_f64(1.0 / 24.0) + x2 * (T::from_f64(1.0 / 1920.0) + x2 * (T::from_f64(1.0 / 322560.0) + T::from_f64(1.0 / 92897280.0) * x2)))),
+      T::ZERO,
     ));
   }
-  let b_max = (0.5 * x).exp();
-  let one_over_b_max = 1.0 / b_max;
-  fabs(max(sel(q < 0.0, -1.0, 1.0) * (b_max - one_over_b_max), 0.0))
+  let b_max = exp(T::HALF * x);
+  let one_over_b_max = T::ONE / b_max;
+  fabs(max(sel(q < T::ZERO, -T::ONE, T::ONE) * (b_max - one_over_b_max), T::ZERO))
 }
 
 ///
-fn normalised_intrinsic_call(x: f64) -> f64 {
-  normalised_intrinsic(x, 1.0)
+fn normalised_intrinsic_call<T: Float>(x: T) -> T {
+  normalised_intrinsic(x, T::ONE)
 }
 
 ///```text
@@ -164,12 +190,12 @@ fn normalised_intrinsic_call(x: f64) -> f64 {
 /// with
 ///             h  =  x/s   and   t  =  s/2
 /// ```
-fn normalised_black_call_using_norm_cdf(x: f64, s: f64) -> f64 {
+fn normalised_black_call_using_norm_cdf<T: Float>(x: T, s: T) -> T {
   let h = x / s;
-  let t = 0.5 * s;
-  let b_max = exp(0.5 * x);
+  let t = T::HALF * s;
+  let b_max = exp(T::HALF * x);
   let b = norm_cdf(h + t) * b_max - norm_cdf(h - t) / b_max;
-  fabs(max(b, 0.0))
+  fabs(max(b, T::ZERO))
 }
 
 ///```text
@@ -209,9 +235,9 @@ fn normalised_black_call_using_norm_cdf(x: f64, s: f64) -> f64 {
 /// square bracket is between the evaluation of two rational functions, which, typically, according to Marsaglia,
 /// retains the full 16 digits of accuracy (or just a little less than that).
 ///```
-fn normalised_black_call_using_erfcx(h: f64, t: f64) -> f64 {
-  let b = 0.5 * exp(-0.5 * (h * h + t * t)) * (erfcx_cody(-ONE_OVER_SQRT_TWO * (h + t)) - erfcx_cody(-ONE_OVER_SQRT_TWO * (h - t)));
-  fabs(max(b, 0.0))
+fn normalised_black_call_using_erfcx<T: Float>(h: T, t: T) -> T {
+  let b = T::HALF * exp(-T::HALF * (h * h + t * t)) * (erfcx_cody(-T::from_f64(ONE_OVER_SQRT_TWO) * (h + t)) - erfcx_cody(-T::from_f64(ONE_OVER_SQRT_TWO) * (h - t)));
+  fabs(max(b, T::ZERO))
 }
 
 ///```text
@@ -234,110 +260,155 @@ fn normalised_black_call_using_erfcx(h: f64, t: f64) -> f64 {
 /// number of exponential function evaluations can be minimised by a judicious choice of one of the above
 /// formulations depending on the input values and the branch logic in Cody's erfc() and erfcx().
 ///```
-fn normalised_black_call_with_optimal_use_of_codys_functions(x: f64, s: f64) -> f64 {
-  let codys_threshold = 0.46875;
+fn normalised_black_call_with_optimal_use_of_codys_functions<T: Float>(x: T, s: T) -> T {
+  let codys_threshold = T::from_f64(0.46875);
   let h = x / s;
-  let t = 0.5 * s;
-  let q1 = -ONE_OVER_SQRT_TWO * (h + t);
-  let q2 = -ONE_OVER_SQRT_TWO * (h - t);
+  let t = T::HALF * s;
+  let q1 = -T::from_f64(ONE_OVER_SQRT_TWO) * (h + t);
+  let q2 = -T::from_f64(ONE_OVER_SQRT_TWO) * (h - t);
   let two_b;
   if q1 < codys_threshold {
     if q2 < codys_threshold {
-      two_b = exp(0.5 * x) * erfc_cody(q1) - exp(-0.5 * x) * erfc_cody(q2);
+      two_b = exp(T::HALF * x) * erfc_cody(q1) - exp(-T::HALF * x) * erfc_cody(q2);
     } else {
-      two_b = exp(0.5 * x) * erfc_cody(q1) - exp(-0.5 * (h * h + t * t)) * erfcx_cody(q2);
+      two_b = exp(T::HALF * x) * erfc_cody(q1) - exp(-T::HALF * (h * h + t * t)) * erfcx_cody(q2);
     }
   } else if q2 < codys_threshold {
-    two_b = exp(-0.5 * (h * h + t * t)) * erfcx_cody(q1) - exp(-0.5 * x) * erfc_cody(q2);
+    two_b = exp(-T::HALF * (h * h + t * t)) * erfcx_cody(q1) - exp(-T::HALF * x) * erfc_cody(q2);
   } else {
-    two_b = exp(-0.5 * (h * h + t * t)) * (erfcx_cody(q1) - erfcx_cody(q2));
+    two_b = exp(-T::HALF * (h * h + t * t)) * (erfcx_cody(q1) - erfcx_cody(q2));
   }
-  fabs(max(0.5 * two_b, 0.0))
+  fabs(max(T::HALF * two_b, T::ZERO))
 }
 
+/// Undiscounted Black price of a call (q=1) or put (q=-1) for the forward `f`, strike `k`, volatility `sigma` and time to expiry `t`.
 ///
-pub fn black(f: f64, k: f64, sigma: f64, t: f64, q: f64 /* q=±1 */) -> f64 {
-  let intrinsic = fabs(max(sel(q < 0.0, k - f, f - k), 0.0));
+/// The price is finite for positive `f` and `k` and non-negative `sigma` and `t`, unless it rounds beyond the largest finite value.
+/// NaN arguments give NaN.
+pub fn black<T: Float>(f: T, k: T, sigma: T, t: T, q: T /* q=±1 */) -> T {
+  if f.is_nan() || k.is_nan() || sigma.is_nan() || t.is_nan() || q.is_nan() {
+    return T::NAN;
+  }
+  let intrinsic = fabs(max(sel(q < T::ZERO, k - f, f - k), T::ZERO));
   // Map in-the-money to out-of-the-money
-  if q * (f - k) > 0.0 {
+  if q * (f - k) > T::ZERO {
     return intrinsic + black(f, k, sigma, t, -q);
   }
   max(intrinsic, (sqrt(f) * sqrt(k)) * normalised_black(log(f / k), sigma * sqrt(t), q))
 }
 
-///
-pub fn normalised_black_call(x: f64, s: f64) -> f64 {
-  if x > 0.0 {
+/// Normalised Black call price  b(x, s) = Φ(x/s + s/2)·exp(x/2) - Φ(x/s - s/2)·exp(-x/2).  NaN arguments give NaN.
+pub fn normalised_black_call<T: Float>(x: T, s: T) -> T {
+  if x.is_nan() || s.is_nan() {
+    return T::NAN;
+  }
+  if x > T::ZERO {
     return normalised_intrinsic_call(x) + normalised_black_call(-x, s); // In the money.
   }
-  if s <= fabs(x) * DENORMALISATION_CUTOFF {
+  if s <= fabs(x) * T::from_f64(DENORMALISATION_CUTOFF) {
     return normalised_intrinsic_call(x); // sigma=0 -> intrinsic value.
   }
   // Denote h := x/s and t := s/2.
   // We evaluate the condition |h|>|η|, i.e., h<η  &&  t < τ+|h|-|η| avoiding any divisions by s,
   // where η = ASYMPTOTIC_EXPANSION_ACCURACY_THRESHOLD  and τ = SMALL_T_EXPANSION_OF_NORMALISED_BLACK_THRESHOLD.
-  let eta = *ASYMPTOTIC_EXPANSION_ACCURACY_THRESHOLD;
-  let tau = *SMALL_T_EXPANSION_OF_NORMALISED_BLACK_THRESHOLD;
-  if x < s * eta && 0.5 * s * s + x < s * (tau + eta) {
-    return asymptotic_expansion_of_normalised_black_call(x / s, 0.5 * s);
+  let eta = T::ASYMPTOTIC_EXPANSION_ACCURACY_THRESHOLD;
+  let tau = T::TWO * T::SIXTEENTH_ROOT_EPSILON;
+  if x < s * eta && T::HALF * s * s + x < s * (tau + eta) {
+    return asymptotic_expansion_of_normalised_black_call(x / s, T::HALF * s);
   }
-  if 0.5 * s < tau {
-    return small_t_expansion_of_normalised_black_call(x / s, 0.5 * s);
+  if T::HALF * s < tau {
+    return small_t_expansion_of_normalised_black_call(x / s, T::HALF * s);
   }
   if DO_NOT_OPTIMISE_NORMALISED_BLACK_IN_REGIONS_3_AND_4_FOR_CODYS_FUNCTIONS {
     // When b is more than, say, about 85% of b_max=exp(x/2), then b is dominated by the first of the two terms in the Black formula, and we retain more accuracy by not attempting to combine the two terms in any way.
     // We evaluate the condition h+t>0.85  avoiding any divisions by s.
-    if x + 0.5 * s * s > s * 0.85 {
+    if x + T::HALF * s * s > s * T::from_f64(0.85) {
       normalised_black_call_using_norm_cdf(x, s)
     } else {
-      normalised_black_call_using_erfcx(x / s, 0.5 * s)
+      normalised_black_call_using_erfcx(x / s, T::HALF * s)
     }
   } else {
     normalised_black_call_with_optimal_use_of_codys_functions(x, s)
   }
 }
 
-///
-pub fn normalised_black(x: f64, s: f64, q: f64 /* q=±1 */) -> f64 {
+/// Normalised Black price of a call (q=1) or put (q=-1) for  x = ln(F/K)  and  s = σ·√T.  NaN arguments give NaN.
+pub fn normalised_black<T: Float>(x: T, s: T, q: T /* q=±1 */) -> T {
+  if q.is_nan() {
+    return T::NAN;
+  }
   /* Reciprocal-strike call-put equivalence */
-  normalised_black_call(sel(q < 0.0, -x, x), s)
+  normalised_black_call(sel(q < T::ZERO, -x, x), s)
+}
+
+/// Natural logarithm of the normalised Black call price  b(x, s),  accurate also where  b(x, s)  underflows.
+///
+/// Prices above √DBL_MIN are evaluated as  ln(b(x, s)).  Below, the out-of-the-money price is evaluated in log space by the
+/// branch of `normalised_black_call` that applies, most notably the asymptotic expansion for large negative  h = x/s.
+/// NaN arguments give NaN.
+pub fn ln_normalised_black_call<T: Float>(x: T, s: T) -> T {
+  let b = normalised_black_call(x, s);
+  if b >= T::SQRT_MIN_POSITIVE || b.is_nan() || x > T::ZERO || s <= T::ZERO {
+    return log(b);
+  }
+  let h = x / s;
+  let t = T::HALF * s;
+  let eta = T::ASYMPTOTIC_EXPANSION_ACCURACY_THRESHOLD;
+  let tau = T::TWO * T::SIXTEENTH_ROOT_EPSILON;
+  if x < s * eta && T::HALF * s * s + x < s * (tau + eta) {
+    return ln_asymptotic_expansion_of_normalised_black_call(h, t);
+  }
+  if t < tau {
+    return ln_small_t_expansion_of_normalised_black_call(h, t);
+  }
+  let q2 = -T::from_f64(ONE_OVER_SQRT_TWO) * (h - t);
+  if h + t <= T::ZERO {
+    // b  =  ½ · exp(-½(h²+t²)) · [ erfcx(-(h+t)/√2) -  erfcx(-(h-t)/√2) ]
+    let q1 = -T::from_f64(ONE_OVER_SQRT_TWO) * (h + t);
+    return log(T::HALF * (erfcx_cody(q1) - erfcx_cody(q2))) - T::HALF * (h * h + t * t);
+  }
+  // Only x < -2·ln(√DBL_MIN) gets here, with  b  =  exp(x/2) · [ Φ(h+t) - ½·erfcx(-(h-t)/√2)·exp(-½(h²+t²)-x/2) ]
+  T::HALF * x + log(norm_cdf(h + t) - T::HALF * erfcx_cody(q2) * exp(-T::HALF * (h * h + t * t) - T::HALF * x))
 }
 
 ///
-pub fn normalised_vega(x: f64, s: f64) -> f64 {
+pub fn normalised_vega<T: Float>(x: T, s: T) -> T {
   let ax = fabs(x);
-  if ax <= 0.0 {
-    ONE_OVER_SQRT_TWO_PI * exp(-0.125 * s * s)
-  } else if s <= 0.0 || s <= ax * *SQRT_DBL_MIN {
-    0.0
+  if ax <= T::ZERO {
+    T::from_f64(ONE_OVER_SQRT_TWO_PI) * exp(-T::from_f64(0.125) * s * s)
+  } else if s <= T::ZERO || s <= ax * T::SQRT_MIN_POSITIVE {
+    T::ZERO
   } else {
-    ONE_OVER_SQRT_TWO_PI * exp(-0.5 * (square(x / s) + square(0.5 * s)))
+    T::from_f64(ONE_OVER_SQRT_TWO_PI) * exp(-T::HALF * (square(x / s) + square(T::HALF * s)))
   }
 }
 
 /// This weeds out denormalised (a.k.a. 'subnormal') numbers.
-fn is_below_horizon(x: f64) -> bool {
-  fabs(x) < DENORMALISATION_CUTOFF
+fn is_below_horizon<T: Float>(x: T) -> bool {
+  fabs(x) < T::from_f64(DENORMALISATION_CUTOFF)
 }
 
 ///
-fn compute_f_lower_map_and_first_two_derivatives(x: f64, s: f64) -> (f64, f64, f64) {
+fn compute_f_lower_map_and_first_two_derivatives<T: Float>(x: T, s: T) -> (T, T, T) {
   let ax = fabs(x);
-  let z = SQRT_ONE_OVER_THREE * ax / s;
+  let z = T::from_f64(SQRT_ONE_OVER_THREE) * ax / s;
   let y = z * z;
   let s2 = s * s;
   let phi_minus = norm_cdf(-z);
   let phi_plus = norm_pdf(z);
-  let fpp = PI_OVER_SIX * y / (s2 * s) * phi_minus * (8.0 * SQRT_THREE * s * ax + (3.0 * s2 * (s2 - 8.0) - 8.0 * x * x) * phi_minus / phi_plus) * exp(2.0 * y + 0.25 * s2);
+  let fpp = T::from_f64(PI_OVER_SIX) * y / (s2 * s)
+    * phi_minus
+    * (T::from_f64(8.0) * T::from_f64(SQRT_THREE) * s * ax + (T::from_f64(3.0) * s2 * (s2 - T::from_f64(8.0)) - T::from_f64(8.0) * x * x) * phi_minus / phi_plus)
+    * exp(T::TWO * y + T::from_f64(0.25) * s2);
   let (f, fp) = if is_below_horizon(s) {
-    (0.0, 1.0)
+    (T::ZERO, T::ONE)
   } else {
     let phi2 = phi_minus * phi_minus;
-    let fp = TWO_PI * y * phi2 * exp(y + 0.125 * s * s);
+    let fp = T::from_f64(TWO_PI) * y * phi2 * exp(y + T::from_f64(0.125) * s * s);
     let f = if is_below_horizon(x) {
-      0.0
+      T::ZERO
     } else {
-      TWO_PI_OVER_SQRT_TWENTY_SEVEN * ax * (phi2 * phi_minus)
+      T::from_f64(TWO_PI_OVER_SQRT_TWENTY_SEVEN) * ax * (phi2 * phi_minus)
     };
     (f, fp)
   };
@@ -345,33 +416,71 @@ fn compute_f_lower_map_and_first_two_derivatives(x: f64, s: f64) -> (f64, f64, f
 }
 
 ///
-fn compute_f_upper_map_and_first_two_derivatives(x: f64, s: f64) -> (f64, f64, f64) {
-  let f = norm_cdf(-0.5 * s);
+fn compute_f_upper_map_and_first_two_derivatives<T: Float>(x: T, s: T) -> (T, T, T) {
+  let f = norm_cdf(-T::HALF * s);
   let (fp, fpp) = if is_below_horizon(x) {
-    let fp = -0.5;
-    let fpp = 0.0;
+    let fp = -T::HALF;
+    let fpp = T::ZERO;
     (fp, fpp)
   } else {
     let w = square(x / s);
-    let fp = -0.5 * exp(0.5 * w);
-    let fpp = SQRT_PI_OVER_TWO * exp(w + 0.125 * s * s) * w / s;
+    let fp = -T::HALF * exp(T::HALF * w);
+    let fpp = T::from_f64(SQRT_PI_OVER_TWO) * exp(w + T::from_f64(0.125) * s * s) * w / s;
     (fp, fpp)
   };
   (f, fp, fpp)
 }
 
 ///
-fn inverse_f_lower_map(x: f64, f: f64) -> f64 {
+fn inverse_f_lower_map<T: Float>(x: T, f: T) -> T {
   sel(
     is_below_horizon(f),
-    0.0,
-    fabs(x / (SQRT_THREE * inverse_norm_cdf(pow(f / (TWO_PI_OVER_SQRT_TWENTY_SEVEN * fabs(x)), 1.0 / 3.0)))),
+    T::ZERO,
+    fabs(x / (T::from_f64(SQRT_THREE) * inverse_norm_cdf(pow(f / (T::from_f64(TWO_PI_OVER_SQRT_TWENTY_SEVEN) * fabs(x)), T::from_f64(1.0 / 3.0))))),
   )
 }
 
 ///
-fn inverse_f_upper_map(f: f64) -> f64 {
-  -2.0 * inverse_norm_cdf(f)
+fn inverse_f_upper_map<T: Float>(f: T) -> T {
+  -T::TWO * inverse_norm_cdf(f)
+}
+
+/// Segment of the price axis on which the initial guess of the implied total volatility is interpolated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RationalGuessSegment {
+  /// β < b_l,  interpolated in the lower map  f_lower(β)  between 0 and  b_l.
+  Lowest,
+  /// b_l ≤ β < b_c,  interpolated in  s(β)  between  b_l  and  b_c.
+  LowerMiddle,
+  /// b_c ≤ β ≤ b_h,  interpolated in  s(β)  between  b_c  and  b_h.
+  UpperMiddle,
+  /// b_h < β,  interpolated in the upper map  f_upper(β)  between  b_h  and  b_max.
+  Highest,
+}
+
+/// Initial guess of the implied total volatility  s = σ·√T  with the breakpoints of the segments, in terms of the
+/// time value  β  of the normalised price of the out-of-the-money call with  x ≤ 0.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RationalGuess<T> {
+  pub segment: RationalGuessSegment,
+  /// Normalised price of the out-of-the-money call, i.e. the normalised time value.
+  pub beta: T,
+  /// Log-moneyness  x = -|ln(F/K)|  of the out-of-the-money call.
+  pub x: T,
+  /// Central breakpoint  s_c = √(2|x|)  at the inflection point of  b(x,s),  and  b_c = b(x,s_c).
+  pub s_c: T,
+  pub b_c: T,
+  /// Lower breakpoint, where the tangent of  b(x,s)  at  s_c  is zero.
+  pub s_l: T,
+  pub b_l: T,
+  /// Upper breakpoint, where the tangent of  b(x,s)  at  s_c  reaches  b_max = exp(x/2).
+  pub s_h: T,
+  pub b_h: T,
+  /// Initial guess, before any iteration.
+  pub s: T,
+  /// Bracket of the implied total volatility given by the segment.
+  pub s_left: T,
+  pub s_right: T,
 }
 
 ///```text
@@ -386,211 +495,260 @@ fn inverse_f_upper_map(f: f64) -> f64 {
 ///     s_n+1  =  s_n  +  newton · [ 1 + halley·newton/2 ] / [ 1 + newton·( halley + hh3·newton/6 ) ]
 ///
 /// NOTE that this function returns 0 when beta<intrinsic without any safety checks.
+/// A prior total volatility `s_prior` takes the place of the initial guess when it lies within the bracket of its segment, and,
+/// in the lowest segment, its price is a normal number. The guess is then not interpolated.
 ///```
-fn unchecked_normalised_implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(mut beta: f64, mut x: f64, mut q: f64 /* q=±1 */, n: usize) -> f64 {
+fn unchecked_normalised_implied_volatility_from_a_transformed_rational_guess_with_limited_iterations<T: Float>(
+  mut beta: T,
+  mut x: T,
+  mut q: T, /* q=±1 */
+  s_prior: Option<T>,
+  n: usize,
+  order: usize,
+) -> (T, usize) {
   // Subtract intrinsic.
-  if q * x > 0.0 {
-    beta = fabs(max(beta - normalised_intrinsic(x, q), 0.0));
+  if q * x > T::ZERO {
+    beta = fabs(max(beta - normalised_intrinsic(x, q), T::ZERO));
     q = -q;
   }
   // Map puts to calls
-  if q < 0.0 {
+  if q < T::ZERO {
     x = -x;
   }
   // For negative or zero prices we return 0.
-  if beta <= 0.0 {
-    return implied_volatility_output(0, 0.0);
+  if beta <= T::ZERO {
+    return (T::ZERO, 0);
   }
   // For positive but denormalised (a.k.a. 'subnormal') prices, we return 0 since it would be impossible to converge to full machine accuracy anyway.
-  if beta < DENORMALISATION_CUTOFF {
-    return implied_volatility_output(0, 0.0);
+  if beta < T::from_f64(DENORMALISATION_CUTOFF) {
+    return (T::ZERO, 0);
   }
-  let b_max = exp(0.5 * x);
+  let b_max = exp(T::HALF * x);
   if beta >= b_max {
-    return implied_volatility_output(0, VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM);
+    return (T::MAX, 0);
   }
-  let mut iterations = 0_usize;
-  let mut direction_reversal_count = 0_usize;
+  let (mut guess, v_c) = rational_guess_bracket(beta, x);
+  // A prior total volatility within the bracket of the segment takes the place of the interpolated initial guess, the objective function
+  // stays that of the segment. In the lowest segment, its price must be a normal number, as the objective function loses its precision below.
+  let is_usable =
+    |s_prior: &T| *s_prior > guess.s_left && *s_prior < guess.s_right && (guess.segment != RationalGuessSegment::Lowest || normalised_black_call(x, *s_prior) >= T::MIN_POSITIVE);
+  guess.s = match s_prior.filter(is_usable) {
+    Some(s_prior) => s_prior,
+    None => interpolated_rational_guess(&guess, v_c),
+  };
+  householder_iterations_from_a_transformed_rational_guess(&guess, n, order)
+}
 
-  let mut f = -DBL_MAX;
-  let mut s = -DBL_MAX;
-  let mut ds = s;
-  let mut ds_previous = 0_f64;
-  let mut s_left = DBL_MIN;
-  let mut s_right = DBL_MAX;
+/// Segment and bracket of the initial guess for the normalised time value  0 < beta < b_max  of an out-of-the-money call  x ≤ 0,
+/// with the guess  s  itself not yet interpolated, and the vega at  s_c.
+fn rational_guess_bracket<T: Float>(beta: T, x: T) -> (RationalGuess<T>, T) {
+  let b_max = exp(T::HALF * x);
   // The temptation is great to use the optimised form b_c = exp(x/2)/2-exp(-x/2)·Phi(sqrt(-2·x)) but that would require implementing all of the above types of round-off and over/underflow handling for this expression, too.
-  let s_c = sqrt(fabs(2.0 * x));
+  let s_c = sqrt(fabs(T::TWO * x));
   let b_c = normalised_black_call(x, s_c);
   let v_c = normalised_vega(x, s_c);
+  let s_l = s_c - b_c / v_c;
+  let s_h = sel(v_c > T::MIN_POSITIVE, s_c + (b_max - b_c) / v_c, s_c);
+  // Only the breakpoint bounding the segment is evaluated.
+  let (mut b_l, mut b_h) = (T::NAN, T::NAN);
+  let (segment, s_left, s_right);
   if beta < b_c {
-    let s_l = s_c - b_c / v_c;
-    let b_l = normalised_black_call(x, s_l);
+    b_l = normalised_black_call(x, s_l);
     if beta < b_l {
+      (segment, s_left, s_right) = (RationalGuessSegment::Lowest, T::MIN_POSITIVE, s_l);
+    } else {
+      (segment, s_left, s_right) = (RationalGuessSegment::LowerMiddle, s_l, s_c);
+    }
+  } else {
+    b_h = normalised_black_call(x, s_h);
+    if beta <= b_h {
+      (segment, s_left, s_right) = (RationalGuessSegment::UpperMiddle, s_c, s_h);
+    } else {
+      (segment, s_left, s_right) = (RationalGuessSegment::Highest, s_h, T::MAX);
+    }
+  }
+  let guess = RationalGuess {
+    segment,
+    beta,
+    x,
+    s_c,
+    b_c,
+    s_l,
+    b_l,
+    s_h,
+    b_h,
+    s: T::NAN,
+    s_left,
+    s_right,
+  };
+  (guess, v_c)
+}
+
+/// Initial guess of the implied total volatility for the normalised time value  0 < beta < b_max  of an out-of-the-money call  x ≤ 0.
+fn transformed_rational_guess<T: Float>(beta: T, x: T) -> RationalGuess<T> {
+  let (mut guess, v_c) = rational_guess_bracket(beta, x);
+  guess.s = interpolated_rational_guess(&guess, v_c);
+  guess
+}
+
+/// Initial guess interpolated within the segment of the bracket  `guess`  of [rational_guess_bracket], with the vega  `v_c`  at  s_c.
+fn interpolated_rational_guess<T: Float>(guess: &RationalGuess<T>, v_c: T) -> T {
+  let RationalGuess {
+    beta,
+    x,
+    s_c,
+    b_c,
+    s_l,
+    b_l,
+    s_h,
+    b_h,
+    ..
+  } = *guess;
+  match guess.segment {
+    RationalGuessSegment::Lowest => {
       let (f_lower_map_l, d_f_lower_map_l_d_beta, d2_f_lower_map_l_d_beta2) = compute_f_lower_map_and_first_two_derivatives(x, s_l);
-      let r_ll =
-        convex_rational_cubic_control_parameter_to_fit_second_derivative_at_right_side(0., b_l, 0., f_lower_map_l, 1., d_f_lower_map_l_d_beta, d2_f_lower_map_l_d_beta2, true);
-      f = rational_cubic_interpolation(beta, 0., b_l, 0., f_lower_map_l, 1., d_f_lower_map_l_d_beta, r_ll);
-      if f.le(&0.0) {
+      let r_ll = convex_rational_cubic_control_parameter_to_fit_second_derivative_at_right_side(
+        T::ZERO,
+        b_l,
+        T::ZERO,
+        f_lower_map_l,
+        T::ONE,
+        d_f_lower_map_l_d_beta,
+        d2_f_lower_map_l_d_beta2,
+        true,
+      );
+      let mut f = rational_cubic_interpolation(beta, T::ZERO, b_l, T::ZERO, f_lower_map_l, T::ONE, d_f_lower_map_l_d_beta, r_ll);
+      if f <= T::ZERO {
         // This can happen due to roundoff truncation for extreme values such as |x|>500.
         // We switch to quadratic interpolation using f(0)≡0, f(b_l), and f'(0)≡1 to specify the quadratic.
         let t = beta / b_l;
-        f = (f_lower_map_l * t + b_l * (1.0 - t)) * t;
-      }
-      s = inverse_f_lower_map(x, f);
-      s_right = s_l;
-      //
-      // In this branch, which comprises the lowest segment, the objective function is
-      //     g(s) = 1/ln(b(x,s)) - 1/ln(beta)
-      //          ≡ 1/ln(b(s)) - 1/ln(beta)
-      // This makes
-      //              g'               =   -b'/(b·ln(b)²)
-      //              newton = -g/g'   =   (ln(beta)-ln(b))·ln(b)/ln(beta)·b/b'
-      //              halley = g''/g'  =   b''/b'  -  b'/b·(1+2/ln(b))
-      //              hh3    = g'''/g' =   b'''/b' +  2(b'/b)²·(1+3/ln(b)·(1+1/ln(b)))  -  3(b''/b)·(1+2/ln(b))
-      //
-      // The Householder(3) iteration is
-      //     s_n+1  =  s_n  +  newton · [ 1 + halley·newton/2 ] / [ 1 + newton·( halley + hh3·newton/6 ) ]
-      //
-      while iterations < n && fabs(ds) > DBL_EPSILON * s {
-        if ds * ds_previous < 0.0 {
-          direction_reversal_count += 1;
-        }
-        if iterations > 0 && (3 == direction_reversal_count || !(s > s_left && s < s_right)) {
-          // If looping inefficiently, or the forecast step takes us outside the bracket, or onto its edges, switch to binary nesting.
-          // NOTE that this can only really happen for very extreme values of |x|, such as |x| = |ln(F/K)| > 500.
-          s = 0.5 * (s_left + s_right);
-          if s_right - s_left <= DBL_EPSILON * s {
-            break;
-          };
-          direction_reversal_count = 0;
-          ds = 0.0;
-        }
-        ds_previous = ds;
-        let b = normalised_black_call(x, s);
-        let bp = normalised_vega(x, s);
-        if b > beta && s < s_right {
-          s_right = s;
-        } else if b < beta && s > s_left {
-          s_left = s;
-        } // Tighten the bracket if applicable.
-        if b <= 0.0 || bp <= 0.0 {
-          // Numerical underflow. Switch to binary nesting for this iteration.
-          ds = 0.5 * (s_left + s_right) - s;
-        } else {
-          let ln_b = log(b);
-          let ln_beta = log(beta);
-          let bpob = bp / b;
-          let h = x / s;
-          let b_halley = h * h / s - s / 4.0;
-          let newton = (ln_beta - ln_b) * ln_b / ln_beta / bpob;
-          let halley = b_halley - bpob * (1.0 + 2.0 / ln_b);
-          let b_hh3 = b_halley * b_halley - 3.0 * square(h / s) - 0.25;
-          let hh3 = b_hh3 + 2.0 * square(bpob) * (1.0 + 3.0 / ln_b * (1.0 + 1.0 / ln_b)) - 3.0 * b_halley * bpob * (1.0 + 2.0 / ln_b);
-          ds = newton * householder_factor(newton, halley, hh3);
-        }
-        ds = max(-0.5 * s, ds);
-        s += ds;
-        iterations += 1;
+        f = (f_lower_map_l * t + b_l * (T::ONE - t)) * t;
       }
-      return implied_volatility_output(iterations, s);
-    } else {
+      inverse_f_lower_map(x, f)
+    }
+    RationalGuessSegment::LowerMiddle => {
       let v_l = normalised_vega(x, s_l);
-      let r_lm = convex_rational_cubic_control_parameter_to_fit_second_derivative_at_right_side(b_l, b_c, s_l, s_c, 1.0 / v_l, 1.0 / v_c, 0.0, false);
-      s = rational_cubic_interpolation(beta, b_l, b_c, s_l, s_c, 1.0 / v_l, 1.0 / v_c, r_lm);
-      s_left = s_l;
-      s_right = s_c;
+      let r_lm = convex_rational_cubic_control_parameter_to_fit_second_derivative_at_right_side(b_l, b_c, s_l, s_c, T::ONE / v_l, T::ONE / v_c, T::ZERO, false);
+      rational_cubic_interpolation(beta, b_l, b_c, s_l, s_c, T::ONE / v_l, T::ONE / v_c, r_lm)
     }
-  } else {
-    let s_h = sel(v_c > DBL_MIN, s_c + (b_max - b_c) / v_c, s_c);
-    let b_h = normalised_black_call(x, s_h);
-    if beta <= b_h {
+    RationalGuessSegment::UpperMiddle => {
       let v_h = normalised_vega(x, s_h);
-      let r_hm = convex_rational_cubic_control_parameter_to_fit_second_derivative_at_left_side(b_c, b_h, s_c, s_h, 1.0 / v_c, 1.0 / v_h, 0.0, false);
-      s = rational_cubic_interpolation(beta, b_c, b_h, s_c, s_h, 1.0 / v_c, 1.0 / v_h, r_hm);
-      s_left = s_c;
-      s_right = s_h;
-    } else {
+      let r_hm = convex_rational_cubic_control_parameter_to_fit_second_derivative_at_left_side(b_c, b_h, s_c, s_h, T::ONE / v_c, T::ONE / v_h, T::ZERO, false);
+      rational_cubic_interpolation(beta, b_c, b_h, s_c, s_h, T::ONE / v_c, T::ONE / v_h, r_hm)
+    }
+    RationalGuessSegment::Highest => {
+      let b_max = exp(T::HALF * x);
+      let mut f = -T::MAX;
       let (f_upper_map_h, d_f_upper_map_h_d_beta, d2_f_upper_map_h_d_beta2) = compute_f_upper_map_and_first_two_derivatives(x, s_h);
-      if d2_f_upper_map_h_d_beta2 > -*SQRT_DBL_MAX && d2_f_upper_map_h_d_beta2 < *SQRT_DBL_MAX {
+      if d2_f_upper_map_h_d_beta2 > -T::SQRT_MAX && d2_f_upper_map_h_d_beta2 < T::SQRT_MAX {
         let r_hh = convex_rational_cubic_control_parameter_to_fit_second_derivative_at_left_side(
           b_h,
           b_max,
           f_upper_map_h,
-          0.,
+          T::ZERO,
           d_f_upper_map_h_d_beta,
-          -0.5,
+          -T::HALF,
           d2_f_upper_map_h_d_beta2,
           true,
         );
-        f = rational_cubic_interpolation(beta, b_h, b_max, f_upper_map_h, 0., d_f_upper_map_h_d_beta, -0.5, r_hh);
+        f = rational_cubic_interpolation(beta, b_h, b_max, f_upper_map_h, T::ZERO, d_f_upper_map_h_d_beta, -T::HALF, r_hh);
       }
-      if f <= 0.0 {
+      if f <= T::ZERO {
         let h = b_max - b_h;
         let t = (beta - b_h) / h;
-        f = (f_upper_map_h * (1.0 - t) + 0.5 * h * t) * (1.0 - t); // We switch to quadratic interpolation using f(b_h), f(b_max)≡0, and f'(b_max)≡-1/2 to specify the quadratic.
-      }
-      s = inverse_f_upper_map(f);
-      s_left = s_h;
-      if beta > 0.5 * b_max {
-        // Else we better drop through and let the objective function be g(s) = b(x,s)-beta.
-        //
-        // In this branch, which comprises the upper segment, the objective function is
-        //     g(s) = ln(b_max-beta)-ln(b_max-b(x,s))
-        //          ≡ ln((b_max-beta)/(b_max-b(s)))
-        // This makes
-        //              g'               =   b'/(b_max-b)
-        //              newton = -g/g'   =   ln((b_max-b)/(b_max-beta))·(b_max-b)/b'
-        //              halley = g''/g'  =   b''/b'  +  b'/(b_max-b)
-        //              hh3    = g'''/g' =   b'''/b' +  g'·(2g'+3b''/b')
-        // and the iteration is
-        //     s_n+1  =  s_n  +  newton · [ 1 + halley·newton/2 ] / [ 1 + newton·( halley + hh3·newton/6 ) ].
-        //
-
-        while iterations < n && fabs(ds) > DBL_EPSILON * s {
-          if ds * ds_previous < 0.0 {
-            direction_reversal_count += 1;
-          }
-          if iterations > 0 && (3 == direction_reversal_count || !(s > s_left && s < s_right)) {
-            // If looping inefficiently, or the forecast step takes us outside the bracket, or onto its edges, switch to binary nesting.
-            // NOTE that this can only really happen for very extreme values of |x|, such as |x| = |ln(F/K)| > 500.
-            s = 0.5 * (s_left + s_right);
-            if s_right - s_left <= DBL_EPSILON * s {
-              break;
-            };
-            direction_reversal_count = 0;
-            ds = 0.0;
-          }
-          ds_previous = ds;
-          let b = normalised_black_call(x, s);
-          let bp = normalised_vega(x, s);
-          if b > beta && s < s_right {
-            // Tighten the bracket if applicable.
-            s_right = s;
-          } else if b < beta && s > s_left {
-            s_left = s;
-          }
-          if b >= b_max || bp <= DBL_MIN {
-            // Numerical underflow. Switch to binary nesting for this iteration.
-            ds = 0.5 * (s_left + s_right) - s;
-          } else {
-            let b_max_minus_b = b_max - b;
-            let g = log((b_max - beta) / b_max_minus_b);
-            let gp = bp / b_max_minus_b;
-            let b_halley = square(x / s) / s - s / 4.0;
-            let b_hh3 = b_halley * b_halley - 3.0 * square(x / (s * s)) - 0.25;
-            let newton = -g / gp;
-            let halley = b_halley + gp;
-            let hh3 = b_hh3 + gp * (2.0 * gp + 3.0 * b_halley);
-            ds = newton * householder_factor(newton, halley, hh3);
-          }
-          ds = max(-0.5 * s, ds);
-          s += ds;
-          iterations += 1;
-        }
-        return implied_volatility_output(iterations, s);
+        f = (f_upper_map_h * (T::ONE - t) + T::HALF * h * t) * (T::ONE - t); // We switch to quadratic interpolation using f(b_h), f(b_max)≡0, and f'(b_max)≡-1/2 to specify the quadratic.
       }
+      inverse_f_upper_map(f)
     }
   }
+}
+
+/// Safeguarded Householder iterations from the initial `guess`, with the objective function of its segment.
+fn householder_iterations_from_a_transformed_rational_guess<T: Float>(guess: &RationalGuess<T>, n: usize, order: usize) -> (T, usize) {
+  let (beta, x) = (guess.beta, guess.x);
+  let b_max = exp(T::HALF * x);
+  if guess.segment == RationalGuessSegment::Lowest {
+    //
+    // In this branch, which comprises the lowest segment, the objective function is
+    //     g(s) = 1/ln(b(x,s)) - 1/ln(beta)
+    //          ≡ 1/ln(b(s)) - 1/ln(beta)
+    // This makes
+    //              g'               =   -b'/(b·ln(b)²)
+    //              newton = -g/g'   =   (ln(beta)-ln(b))·ln(b)/ln(beta)·b/b'
+    //              halley = g''/g'  =   b''/b'  -  b'/b·(1+2/ln(b))
+    //              hh3    = g'''/g' =   b'''/b' +  2(b'/b)²·(1+3/ln(b)·(1+1/ln(b)))  -  3(b''/b)·(1+2/ln(b))
+    //
+    // The Householder(3) iteration is
+    //     s_n+1  =  s_n  +  newton · [ 1 + halley·newton/2 ] / [ 1 + newton·( halley + hh3·newton/6 ) ]
+    //
+    // Since g(s) is decreasing, the bracket is tightened by the sign of -g(s), i.e. that of b-beta.
+    //
+    let objective = |s: T| {
+      let b = normalised_black_call(x, s);
+      let bp = normalised_vega(x, s);
+      let ratios = if b <= T::ZERO || bp <= T::ZERO {
+        // Numerical underflow. Switch to binary nesting for this iteration.
+        None
+      } else {
+        let ln_b = log(b);
+        let ln_beta = log(beta);
+        let bpob = bp / b;
+        let h = x / s;
+        let b_halley = h * h / s - s / T::from_f64(4.0);
+        let newton = (ln_beta - ln_b) * ln_b / ln_beta / bpob;
+        let halley = b_halley - bpob * (T::ONE + T::TWO / ln_b);
+        let b_hh3 = b_halley * b_halley - T::from_f64(3.0) * square(h / s) - T::from_f64(0.25);
+        let hh3 = b_hh3 + T::TWO * square(bpob) * (T::ONE + T::from_f64(3.0) / ln_b * (T::ONE + T::ONE / ln_b)) - T::from_f64(3.0) * b_halley * bpob * (T::ONE + T::TWO / ln_b);
+        Some(HouseholderRatios {
+          newton,
+          halley,
+          hh3,
+          scale: T::ONE,
+        })
+      };
+      HouseholderEvaluation { g: b - beta, ratios }
+    };
+    return safeguarded_householder_root(objective, guess.s, guess.s_left, guess.s_right, order, n);
+  }
+  if guess.segment == RationalGuessSegment::Highest && beta > T::HALF * b_max {
+    // Else we better drop through and let the objective function be g(s) = b(x,s)-beta.
+    //
+    // In this branch, which comprises the upper segment, the objective function is
+    //     g(s) = ln(b_max-beta)-ln(b_max-b(x,s))
+    //          ≡ ln((b_max-beta)/(b_max-b(s)))
+    // This makes
+    //              g'               =   b'/(b_max-b)
+    //              newton = -g/g'   =   ln((b_max-b)/(b_max-beta))·(b_max-b)/b'
+    //              halley = g''/g'  =   b''/b'  +  b'/(b_max-b)
+    //              hh3    = g'''/g' =   b'''/b' +  g'·(2g'+3b''/b')
+    // and the iteration is
+    //     s_n+1  =  s_n  +  newton · [ 1 + halley·newton/2 ] / [ 1 + newton·( halley + hh3·newton/6 ) ].
+    //
+    // The bracket is tightened by the sign of b-beta, which is that of g(s).
+    //
+    let objective = |s: T| {
+      let b = normalised_black_call(x, s);
+      let bp = normalised_vega(x, s);
+      let ratios = if b >= b_max || bp <= T::MIN_POSITIVE {
+        // Numerical underflow. Switch to binary nesting for this iteration.
+        None
+      } else {
+        let b_max_minus_b = b_max - b;
+        let g = log((b_max - beta) / b_max_minus_b);
+        let gp = bp / b_max_minus_b;
+        let b_halley = square(x / s) / s - s / T::from_f64(4.0);
+        let b_hh3 = b_halley * b_halley - T::from_f64(3.0) * square(x / (s * s)) - T::from_f64(0.25);
+        Some(HouseholderRatios {
+          newton: -g / gp,
+          halley: b_halley + gp,
+          hh3: b_hh3 + gp * (T::TWO * gp + T::from_f64(3.0) * b_halley),
+          scale: T::ONE,
+        })
+      };
+      HouseholderEvaluation { g: b - beta, ratios }
+    };
+    return safeguarded_householder_root(objective, guess.s, guess.s_left, guess.s_right, order, n);
+  }
   // In this branch, which comprises the two middle segments, the objective function is g(s) = b(x,s)-beta, or g(s) = b(s) - beta, for short.
   // This makes
   //              newton = -g/g'   =  -(b-beta)/b'
@@ -598,77 +756,177 @@ fn unchecked_normalised_implied_volatility_from_a_transformed_rational_guess_wit
   //              hh3    = g'''/g' =    b'''/b'   =  halley² - 3·(x/s²)² - 1/4
   // and the iteration is
   //     s_n+1  =  s_n  +  newton · [ 1 + halley·newton/2 ] / [ 1 + newton·( halley + hh3·newton/6 ) ].
+  // The correction only depends on halley·newton and hh3·newton², which are evaluated as (s·halley)·(newton/s) and (s²·hh3)·(newton/s)²
+  // with s·halley = h²-s²/4 and s²·hh3 = (s·halley)² - 3·h² - s²/4 for h = x/s, since x/s² overflows or turns into 0/0 for tiny s.
   //
-  while iterations < n && fabs(ds) > DBL_EPSILON * s {
-    if ds * ds_previous < 0.0 {
-      direction_reversal_count += 1;
-    }
-    if iterations > 0 && (3 == direction_reversal_count || !(s > s_left && s < s_right)) {
-      // If looping inefficiently, or the forecast step takes us outside the bracket, or onto its edges, switch to binary nesting.
-      // NOTE that this can only really happen for very extreme values of |x|, such as |x| = |ln(F/K)| > 500.
-      s = 0.5 * (s_left + s_right);
-      if s_right - s_left <= DBL_EPSILON * s {
-        break;
-      };
-      direction_reversal_count = 0;
-      ds = 0.0;
-    }
-    ds_previous = ds;
+  let objective = |s: T| {
     let b = normalised_black_call(x, s);
     let bp = normalised_vega(x, s);
-
-    if b > beta && s < s_right {
-      // Tighten the bracket if applicable.
-      s_right = s;
-    } else if b < beta && s > s_left {
-      s_left = s;
+    let h = x / s;
+    let s_halley = h * h - square(T::HALF * s);
+    HouseholderEvaluation {
+      g: b - beta,
+      ratios: Some(HouseholderRatios {
+        newton: (beta - b) / bp,
+        halley: s_halley,
+        hh3: s_halley * s_halley - T::from_f64(3.0) * h * h - square(T::HALF * s),
+        scale: s,
+      }),
     }
-    let newton = (beta - b) / bp;
-    let halley = square(x / s) / s - s / 4.0;
-    let hh3 = halley * halley - 3.0 * square(x / (s * s)) - 0.25;
-    ds = max(-0.5 * s, newton * householder_factor(newton, halley, hh3));
-    s += ds;
-    iterations += 1;
-  }
-  implied_volatility_output(iterations, s)
+  };
+  safeguarded_householder_root(objective, guess.s, guess.s_left, guess.s_right, order, n)
 }
 
 ///
-pub fn implied_volatility_from_a_transformed_rational_guess(price: f64, f: f64, k: f64, t: f64, q: f64 /* q=±1 */) -> f64 {
+pub fn implied_volatility_from_a_transformed_rational_guess<T: Float>(price: T, f: T, k: T, t: T, q: T /* q=±1 */) -> T {
   implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(price, f, k, t, q, get_implied_volatility_maximum_iterations())
 }
 
+/// Implied volatility from the undiscounted price of a call (q=1) or put (q=-1), using at most `n` iterations.
 ///
-pub fn implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(mut price: f64, f: f64, k: f64, t: f64, mut q: f64 /* q=±1 */, n: usize) -> f64 {
-  let intrinsic = fabs(max(sel(q < 0.0, k - f, f - k), 0.0));
+/// The result is finite for a non-negative `price` and positive `f`, `k` and `t`. Prices below the intrinsic value give
+/// [VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC], prices at or above the maximum price give
+/// [VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM]. NaN arguments give NaN.
+pub fn implied_volatility_from_a_transformed_rational_guess_with_limited_iterations<T: Float>(price: T, f: T, k: T, t: T, q: T /* q=±1 */, n: usize) -> T {
+  let (sigma, iterations) = implied_volatility_and_iteration_count(price, f, k, t, q, None, n, implied_volatility_householder_method_order());
+  implied_volatility_output(iterations, sigma)
+}
+
+/// Implied volatility as returned by [implied_volatility_from_a_transformed_rational_guess_with_limited_iterations],
+/// together with the number of iterations it took, independently of the output type, for the Householder method of the given `order`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn implied_volatility_and_iteration_count<T: Float>(
+  mut price: T,
+  f: T,
+  k: T,
+  t: T,
+  mut q: T, /* q=±1 */
+  sigma_prior: Option<T>,
+  n: usize,
+  order: usize,
+) -> (T, usize) {
+  if price.is_nan() || f.is_nan() || k.is_nan() || t.is_nan() || q.is_nan() {
+    return (T::NAN, 0);
+  }
+  let intrinsic = fabs(max(sel(q < T::ZERO, k - f, f - k), T::ZERO));
   if price < intrinsic {
-    return implied_volatility_output(0, VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC);
+    return (-T::MAX, 0);
   }
-  let max_price = sel(q < 0.0, k, f);
+  let max_price = sel(q < T::ZERO, k, f);
   if price >= max_price {
-    return implied_volatility_output(0, VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM);
+    return (T::MAX, 0);
   }
-  let x = (f / k).ln();
+  let x = log(f / k);
   // Map in-the-money to out-of-the-money
-  if q * x > 0.0 {
-    price = fabs(max(price - intrinsic, 0.0));
+  if q * x > T::ZERO {
+    price = fabs(max(price - intrinsic, T::ZERO));
     q = -q;
   }
-  unchecked_normalised_implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(price / (f.sqrt() * k.sqrt()), x, q, n) / t.sqrt()
+  let (s, iterations) = unchecked_normalised_implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(
+    price / (sqrt(f) * sqrt(k)),
+    x,
+    q,
+    sigma_prior.map(|sigma| sigma * sqrt(t)),
+    n,
+    order,
+  );
+  // The normalised price may round onto its bounds next to the maximum price, the signal values must not overflow when scaled.
+  if fabs(s) == T::MAX {
+    return (s, iterations);
+  }
+  (s / sqrt(t), iterations)
 }
 
-pub fn normalised_implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(mut beta: f64, x: f64, mut q: f64 /* q=±1 */, n: usize) -> f64 {
+/// Implied total volatility  s = σ·√T  from the normalised price of a call (q=1) or put (q=-1), using at most `n` iterations.
+/// NaN arguments give NaN.
+pub fn normalised_implied_volatility_from_a_transformed_rational_guess_with_limited_iterations<T: Float>(beta: T, x: T, q: T /* q=±1 */, n: usize) -> T {
+  let (s, iterations) = normalised_implied_volatility_and_iteration_count(beta, x, q, None, n, implied_volatility_householder_method_order());
+  implied_volatility_output(iterations, s)
+}
+
+/// Implied total volatility as returned by [normalised_implied_volatility_from_a_transformed_rational_guess_with_limited_iterations],
+/// together with the number of iterations it took, independently of the output type, for the Householder method of the given `order`.
+pub(crate) fn normalised_implied_volatility_and_iteration_count<T: Float>(mut beta: T, x: T, mut q: T /* q=±1 */, s_prior: Option<T>, n: usize, order: usize) -> (T, usize) {
+  if beta.is_nan() || x.is_nan() || q.is_nan() {
+    return (T::NAN, 0);
+  }
   // Map in-the-money to out-of-the-money
-  if q * x > 0.0 {
+  if q * x > T::ZERO {
     beta -= normalised_intrinsic(x, q);
     q = -q;
   }
-  if beta < 0.0 {
-    return implied_volatility_output(0, VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC);
+  if beta < T::ZERO {
+    return (-T::MAX, 0);
   }
-  unchecked_normalised_implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(beta, x, q, n)
+  unchecked_normalised_implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(beta, x, q, s_prior, n, order)
 }
 
-pub fn normalised_implied_volatility_from_a_transformed_rational_guess(beta: f64, x: f64, q: f64 /* q=±1 */) -> f64 {
+pub fn normalised_implied_volatility_from_a_transformed_rational_guess<T: Float>(beta: T, x: T, q: T /* q=±1 */) -> T {
   normalised_implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(beta, x, q, get_implied_volatility_maximum_iterations())
 }
+
+/// Implied volatility from the undiscounted price of a call (q=1) or put (q=-1), iterating from the volatility `sigma_prior`
+/// of a previous solution instead of the rational guess.
+///
+/// The prior is used when it lies within the bracket of the segment the rational guess would be interpolated on, which saves the
+/// interpolation. The same safeguarded Householder iteration as that of [implied_volatility_from_a_transformed_rational_guess], with
+/// the same number of iterations, then runs from the prior, and from the rational guess itself reproduces the latter bit for bit.
+/// From elsewhere, the last step rounds to a neighbouring float where the price does not resolve the last bits of the root, as do
+/// cold solves with a different number of iterations. Otherwise the solve starts from the rational guess, with the same result.
+/// Bounds and NaN arguments are handled alike.
+pub fn implied_volatility_from_a_transformed_rational_guess_with_prior<T: Float>(price: T, f: T, k: T, t: T, q: T /* q=±1 */, sigma_prior: T) -> T {
+  let (sigma, iterations) = implied_volatility_and_iteration_count(
+    price,
+    f,
+    k,
+    t,
+    q,
+    Some(sigma_prior),
+    get_implied_volatility_maximum_iterations(),
+    implied_volatility_householder_method_order(),
+  );
+  implied_volatility_output(iterations, sigma)
+}
+
+/// Implied total volatility from the normalised price of a call (q=1) or put (q=-1), iterating from the total volatility
+/// `s_prior` of a previous solution, see [implied_volatility_from_a_transformed_rational_guess_with_prior].
+pub fn normalised_implied_volatility_from_a_transformed_rational_guess_with_prior<T: Float>(beta: T, x: T, q: T /* q=±1 */, s_prior: T) -> T {
+  let (s, iterations) = normalised_implied_volatility_and_iteration_count(
+    beta,
+    x,
+    q,
+    Some(s_prior),
+    get_implied_volatility_maximum_iterations(),
+    implied_volatility_householder_method_order(),
+  );
+  implied_volatility_output(iterations, s)
+}
+
+/// Initial guess of the implied total volatility for the normalised price `beta` of a call (q=1) or put (q=-1), with the
+/// segment it lies in and the breakpoints of the segments, without any iteration.
+///
+/// Returns `None` for NaN arguments and when there is nothing to iterate, i.e. for prices below or at the intrinsic value,
+/// denormalised time values, and prices at or above the maximum price.
+pub fn normalised_transformed_rational_guess<T: Float>(mut beta: T, mut x: T, mut q: T /* q=±1 */) -> Option<RationalGuess<T>> {
+  if beta.is_nan() || x.is_nan() || q.is_nan() {
+    return None;
+  }
+  // Subtract intrinsic.
+  if q * x > T::ZERO {
+    beta -= normalised_intrinsic(x, q);
+    q = -q;
+  }
+  // Map puts to calls
+  if q < T::ZERO {
+    x = -x;
+  }
+  if beta <= T::ZERO || beta < T::from_f64(DENORMALISATION_CUTOFF) || beta >= exp(T::HALF * x) {
+    return None;
+  }
+  let mut guess = transformed_rational_guess(beta, x);
+  match guess.segment {
+    RationalGuessSegment::Lowest | RationalGuessSegment::LowerMiddle => guess.b_h = normalised_black_call(x, guess.s_h),
+    RationalGuessSegment::UpperMiddle | RationalGuessSegment::Highest => guess.b_l = normalised_black_call(x, guess.s_l),
+  }
+  Some(guess)
+}
@@ -1,14 +1,60 @@
-extern crate lazy_static;
+#![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("either the `std` or the `libm` feature must be enabled");
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+mod arbitrage;
+mod bid_ask;
 mod definitions;
+mod delta;
+mod discounted;
 mod erf_cody;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod float;
+#[cfg(feature = "alloc")]
+mod fx;
+mod greeks;
+mod householder;
 mod lets_be_rational;
+mod ln_price;
 mod normal_distribution;
+#[cfg(feature = "alloc")]
+mod parity;
+#[cfg(feature = "python")]
+mod python;
+mod quote;
 mod rational_cubic;
+mod solve;
+mod total_variance;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
+#[cfg(feature = "alloc")]
+pub use arbitrage::{check_static_arbitrage, ArbitrageViolation, CallPriceQuote};
+pub use bid_ask::{bid_ask_implied_volatility, BidAskImpliedVolatility};
+pub use definitions::{VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM, VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC};
+pub use delta::{black_delta, strike_from_delta, DeltaConvention};
+pub use discounted::{discounted_black, implied_volatility_from_discounted_price, implied_volatility_from_discounted_price_and_rate};
 pub use erf_cody::{erf_cody, erfc_cody, erfcx_cody};
+pub use float::Float;
+#[cfg(feature = "alloc")]
+pub use fx::{atm_strike, fx_market_quote_from_smile, fx_smile_from_market_quote, fx_smile_point_from_price, AtmConvention, FxMarketQuote, FxPillar, FxSmilePoint, FxWingQuote};
+pub use greeks::{black_greeks, black_vega, BlackGreeks};
+pub use householder::{safeguarded_householder_root, HouseholderEvaluation, HouseholderRatios};
 pub use lets_be_rational::{
-  black, implied_volatility_from_a_transformed_rational_guess, implied_volatility_from_a_transformed_rational_guess_with_limited_iterations, normalised_black,
-  normalised_black_call, normalised_implied_volatility_from_a_transformed_rational_guess, normalised_implied_volatility_from_a_transformed_rational_guess_with_limited_iterations,
-  normalised_vega,
+  black, implied_volatility_from_a_transformed_rational_guess, implied_volatility_from_a_transformed_rational_guess_with_limited_iterations,
+  implied_volatility_from_a_transformed_rational_guess_with_prior, ln_normalised_black_call, normalised_black, normalised_black_call,
+  normalised_implied_volatility_from_a_transformed_rational_guess, normalised_implied_volatility_from_a_transformed_rational_guess_with_limited_iterations,
+  normalised_implied_volatility_from_a_transformed_rational_guess_with_prior, normalised_transformed_rational_guess, normalised_vega, RationalGuess, RationalGuessSegment,
 };
+pub use ln_price::{implied_volatility_from_ln_price, normalised_implied_volatility_from_ln_price};
+#[cfg(feature = "alloc")]
+pub use parity::{implied_forward_from_put_call_parity, implied_volatility_smile_from_put_call_parity, CallPutQuote, ImpliedForward, ParitySmile, ParitySmilePoint};
+pub use quote::{Expiry, Forward, OptionKind, OptionQuote, Strike, Volatility};
+pub use solve::{SolveResult, SolverConfig};
+pub use total_variance::{black_from_total_variance, forward_normalised_black_from_total_variance, implied_total_variance, implied_total_variance_from_forward_normalised_price};
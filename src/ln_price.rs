@@ -0,0 +1,106 @@
+//!
+//! Implied volatilities from the natural logarithm of the price.
+//!
+//! Deep out-of-the-money prices may be far below the smallest positive number, when the price itself cannot
+//! be passed to `implied_volatility_from_a_transformed_rational_guess`. Given  ℓ = ln(β)  of the normalised
+//! price  β,  the implied total volatility is the root of
+//!
+//! ```text
+//!     g(s)  =  ln(b(x,s)) - ℓ      with     g'(s)  =  ∂b/∂s / b  =  exp(ln(∂b/∂s) - ln(b))
+//! ```
+//!
+//! which is found by Newton iterations safeguarded by a bracket, started from the root of the leading term
+//! -½·(x²/s² + s²/4)  of  ln(b(x,s))  for large negative  x/s,  or from  b(0,s) ≈ s/√(2π)  next to the money.
+//! Prices above √DBL_MIN are exponentiated and inverted by the rational guess and Householder iterations as usual.
+//!
+
+use crate::definitions::*;
+use crate::float::Float;
+use crate::lets_be_rational::*;
+use crate::normal_distribution::{ONE_OVER_SQRT_TWO_PI, SQRT_TWO_PI};
+
+/// Maximum number of Newton iterations in log space.
+const LN_PRICE_INVERSION_MAXIMUM_ITERATIONS: usize = 100;
+
+/// Implied total volatility  s = σ·√T  from the natural logarithm `ln_beta` of the normalised price of a call (q=1) or put (q=-1).
+///
+/// Prices at or above the maximum price give [VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM],
+/// in-the-money prices below the intrinsic value give [VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC]. NaN arguments give NaN.
+pub fn normalised_implied_volatility_from_ln_price<T: Float>(ln_beta: T, x: T, q: T /* q=±1 */) -> T {
+  let (s, iterations) = normalised_implied_volatility_from_ln_price_and_iteration_count(ln_beta, x, q);
+  implied_volatility_output(iterations, s)
+}
+
+/// Implied volatility from the natural logarithm `ln_price` of the undiscounted price of a call (q=1) or put (q=-1).
+///
+/// Prices at or above the maximum price give [VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM],
+/// in-the-money prices below the intrinsic value give [VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC]. NaN arguments give NaN.
+pub fn implied_volatility_from_ln_price<T: Float>(ln_price: T, f: T, k: T, t: T, q: T /* q=±1 */) -> T {
+  if t.is_nan() {
+    return T::NAN;
+  }
+  let ln_beta = ln_price - T::HALF * (log(f) + log(k));
+  let (s, iterations) = normalised_implied_volatility_from_ln_price_and_iteration_count(ln_beta, log(f / k), q);
+  // The signal values must not overflow when scaled.
+  implied_volatility_output(iterations, sel(fabs(s) == T::MAX, s, s / sqrt(t)))
+}
+
+/// Implied total volatility together with the number of iterations it took, independently of the output type.
+fn normalised_implied_volatility_from_ln_price_and_iteration_count<T: Float>(ln_beta: T, x: T, q: T /* q=±1 */) -> (T, usize) {
+  if ln_beta.is_nan() || x.is_nan() || q.is_nan() {
+    return (T::NAN, 0);
+  }
+  if q * x > T::ZERO || ln_beta >= log(T::SQRT_MIN_POSITIVE) {
+    return normalised_implied_volatility_and_iteration_count(
+      exp(ln_beta),
+      x,
+      q,
+      None,
+      get_implied_volatility_maximum_iterations(),
+      implied_volatility_householder_method_order(),
+    );
+  }
+  // Map puts to calls, out of the money  x ≤ 0.
+  let x = sel(q < T::ZERO, -x, x);
+  if ln_beta >= T::HALF * x {
+    return (T::MAX, 0);
+  }
+  // Smaller root of  x²/(2s²) + s²/8 = -ℓ,  which exists since  -ℓ > -x/2,  or  b(0,s) ≈ s/√(2π)  next to the money.
+  let l = -ln_beta;
+  let mut s = max(
+    sqrt(T::TWO * x * (x / l) / (T::TWO + sqrt(T::from_f64(4.0) - square(x / l)))),
+    T::from_f64(SQRT_TWO_PI) * exp(ln_beta),
+  );
+  if s.is_nan() || s <= T::ZERO {
+    // Prices below the smallest positive number at the money, like zero prices, give zero.
+    return (T::ZERO, 0);
+  }
+  let (mut s_left, mut s_right) = (T::ZERO, T::MAX);
+  let mut iterations = 0;
+  while iterations < LN_PRICE_INVERSION_MAXIMUM_ITERATIONS {
+    iterations += 1;
+    let ln_b = ln_normalised_black_call(x, s);
+    let g = ln_b - ln_beta;
+    if fabs(g) <= T::EPSILON * fabs(ln_beta) {
+      // ln(b)  is only resolved to this accuracy.
+      break;
+    }
+    if g < T::ZERO {
+      s_left = s;
+    } else {
+      s_right = s;
+    }
+    let ln_vega = log(T::from_f64(ONE_OVER_SQRT_TWO_PI)) - T::HALF * (square(x / s) + square(T::HALF * s));
+    let ds = -g / exp(ln_vega - ln_b);
+    if fabs(ds) <= T::EPSILON * s {
+      s += ds;
+      break;
+    }
+    s += ds;
+    if !(s > s_left && s < s_right) {
+      // The step leaves the bracket, bisect it or, without upper bound yet, double.
+      s = sel(s_right < T::MAX, T::HALF * (s_left + s_right), T::TWO * max(s_left, s - ds));
+    }
+  }
+  (s, iterations)
+}
@@ -1,60 +1,69 @@
 use crate::definitions::*;
 use crate::erf_cody::*;
-use lazy_static::lazy_static;
+use crate::float::Float;
 
-lazy_static! {
-   ///``` text
-   /// The asymptotic expansion  Φ(z) = φ(z)/|z|·[1-1/z^2+...],  Abramowitz & Stegun (26.2.12), suffices for Φ(z) to have
-   /// relative accuracy of 1.64E-16 for z<=-10 with 17 terms inside the square brackets (not counting the leading 1).
-   /// This translates to a maximum of about 9 iterations below, which is competitive with a call to erfc() and never
-   /// less accurate when z<=-10. Note that, as mentioned in section 4 (and discussion of figures 2 and 3) of George
-   /// Marsaglia's article "Evaluating the Normal Distribution" (available at http://www.jstatsoft.org/v11/a05/paper),
-   /// for values of x approaching -8 and below, the error of any cumulative normal function is actually dominated by
-   /// the hardware (or compiler implementation) accuracy of exp(-x²/2) which is not reliably more than 14 digits when
-   /// x becomes large. Still, we should switch to the asymptotic only when it is beneficial to do so.
-   /// ```
-   static ref NORM_CDF_ASYMPTOTIC_EXPANSION_FIRST_THRESHOLD: f64 = -10.0;
-   static ref NORM_CDF_ASYMPTOTIC_EXPANSION_SECOND_THRESHOLD: f64 = -1.0/sqrt(DBL_EPSILON);
+/// Below  -1/√ε  the asymptotic expansion is exact to machine precision with its leading term.
+fn norm_cdf_asymptotic_expansion_second_threshold<T: Float>() -> T {
+  -T::ONE / T::SQRT_EPSILON
 }
 
-pub const ONE_OVER_SQRT_TWO: f64 = std::f64::consts::FRAC_1_SQRT_2;
+pub const ONE_OVER_SQRT_TWO: f64 = core::f64::consts::FRAC_1_SQRT_2;
 #[allow(clippy::excessive_precision)]
 pub const ONE_OVER_SQRT_TWO_PI: f64 = 0.3989422804014326779399460599343818684758586311649;
 #[allow(clippy::excessive_precision)]
 pub const SQRT_TWO_PI: f64 = 2.506628274631000502415765284811045253006986740610;
 
-pub fn norm_cdf(z: f64) -> f64 {
-  if z <= *NORM_CDF_ASYMPTOTIC_EXPANSION_FIRST_THRESHOLD {
+///``` text
+/// The asymptotic expansion  Φ(z) = φ(z)/|z|·[1-1/z^2+...],  Abramowitz & Stegun (26.2.12), suffices for Φ(z) to have
+/// relative accuracy of 1.64E-16 for z<=-10 with 17 terms inside the square brackets (not counting the leading 1).
+/// This translates to a maximum of about 9 iterations below, which is competitive with a call to erfc() and never
+/// less accurate when z<=-10. Note that, as mentioned in section 4 (and discussion of figures 2 and 3) of George
+/// Marsaglia's article "Evaluating the Normal Distribution" (available at http://www.jstatsoft.org/v11/a05/paper),
+/// for values of x approaching -8 and below, the error of any cumulative normal function is actually dominated by
+/// the hardware (or compiler implementation) accuracy of exp(-x²/2) which is not reliably more than 14 digits when
+/// x becomes large. Still, we should switch to the asymptotic only when it is beneficial to do so.
+/// In single precision, a relative accuracy of 1.1E-8 is reached for z<=-6 already, see Float::NORM_CDF_ASYMPTOTIC_EXPANSION_FIRST_THRESHOLD.
+/// ```
+pub fn norm_cdf<T: Float>(z: T) -> T {
+  if z <= T::NORM_CDF_ASYMPTOTIC_EXPANSION_FIRST_THRESHOLD {
     // Asymptotic expansion for very negative z following (26.2.12) on page 408
     // in M. Abramowitz and A. Stegun, Pocketbook of Mathematical Functions, ISBN 3-87144818-4.
-    let mut sum = 1.0;
-    if z >= *NORM_CDF_ASYMPTOTIC_EXPANSION_SECOND_THRESHOLD {
+    let mut sum = T::ONE;
+    if z >= norm_cdf_asymptotic_expansion_second_threshold() {
       let z_square = z * z;
       let mut i = 1;
-      let mut g = 1.0;
-      let mut a = DBL_MAX;
+      let mut g = T::ONE;
+      let mut a = T::MAX;
       let mut last_a;
       loop {
         last_a = a;
-        let x = (4 * i - 3) as f64 / z_square;
-        let y = x * ((4 * i - 1) as f64 / z_square);
+        let x = T::from_f64((4 * i - 3) as f64) / z_square;
+        let y = x * (T::from_f64((4 * i - 1) as f64) / z_square);
         a = g * (x - y);
         sum -= a;
         g *= y;
         i += 1;
         a = fabs(a);
-        if !(last_a > a && a >= fabs(sum * DBL_EPSILON)) {
+        if !(last_a > a && a >= fabs(sum * T::EPSILON)) {
           break;
         };
       }
       return -norm_pdf(z) * sum / z;
     }
   }
-  0.5 * erfc_cody(-z * ONE_OVER_SQRT_TWO)
+  T::HALF * erfc_cody(-z * T::from_f64(ONE_OVER_SQRT_TWO))
+}
+
+/// Evaluates the polynomial with the given coefficients, lowest order first, by Horner's scheme.
+fn polynomial<T: Float>(coefficients: &[f64], x: T) -> T {
+  match coefficients.split_last() {
+    Some((&last, rest)) => rest.iter().rev().fold(T::from_f64(last), |sum, &coefficient| sum * x + T::from_f64(coefficient)),
+    None => T::ZERO,
+  }
 }
 
 #[allow(clippy::excessive_precision)]
-pub fn inverse_norm_cdf(u: f64) -> f64 {
+pub fn inverse_norm_cdf<T: Float>(u: T) -> T {
   //
   // ALGORITHM AS241  APPL. STATIST. (1988) VOL. 37, NO. 3
   //
@@ -116,32 +125,49 @@ pub fn inverse_norm_cdf(u: f64) -> f64 {
   const F6: f64 = 1.42151175831644588870E-7;
   const F7: f64 = 2.044_263_103_389_939_785_64E-15;
 
-  if u <= 0.0 {
+  if u <= T::ZERO {
     return log(u);
   }
-  if u >= 1.0 {
-    return log(1.0 - u);
+  if u >= T::ONE {
+    return log(T::ONE - u);
   }
 
-  let q = u - 0.5;
-  if fabs(q) <= SPLIT1 {
-    let r = CONST1 - q * q;
-    q * (((((((A7 * r + A6) * r + A5) * r + A4) * r + A3) * r + A2) * r + A1) * r + A0) / (((((((B7 * r + B6) * r + B5) * r + B4) * r + B3) * r + B2) * r + B1) * r + 1.0)
+  let q = u - T::HALF;
+  if fabs(q) <= T::from_f64(SPLIT1) {
+    let r = T::from_f64(CONST1) - q * q;
+    q * polynomial(&[A0, A1, A2, A3, A4, A5, A6, A7], r) / polynomial(&[1.0, B1, B2, B3, B4, B5, B6, B7], r)
   } else {
-    let mut r = sel(q < 0.0, u, 1.0 - u);
+    let mut r = sel(q < T::ZERO, u, T::ONE - u);
     r = sqrt(-log(r));
 
-    let ret = if r < SPLIT2 {
-      r -= CONST2;
-      (((((((C7 * r + C6) * r + C5) * r + C4) * r + C3) * r + C2) * r + C1) * r + C0) / (((((((D7 * r + D6) * r + D5) * r + D4) * r + D3) * r + D2) * r + D1) * r + 1.0)
+    let ret = if r < T::from_f64(SPLIT2) {
+      r -= T::from_f64(CONST2);
+      polynomial(&[C0, C1, C2, C3, C4, C5, C6, C7], r) / polynomial(&[1.0, D1, D2, D3, D4, D5, D6, D7], r)
     } else {
-      r -= SPLIT2;
-      (((((((E7 * r + E6) * r + E5) * r + E4) * r + E3) * r + E2) * r + E1) * r + E0) / (((((((F7 * r + F6) * r + F5) * r + F4) * r + F3) * r + F2) * r + F1) * r + 1.0)
+      r -= T::from_f64(SPLIT2);
+      polynomial(&[E0, E1, E2, E3, E4, E5, E6, E7], r) / polynomial(&[1.0, F1, F2, F3, F4, F5, F6, F7], r)
     };
-    sel(q < 0.0, -ret, ret)
+    sel(q < T::ZERO, -ret, ret)
   }
 }
 
-pub fn norm_pdf(x: f64) -> f64 {
-  ONE_OVER_SQRT_TWO_PI * exp(-0.5 * x * x)
+pub fn norm_pdf<T: Float>(x: T) -> T {
+  T::from_f64(ONE_OVER_SQRT_TWO_PI) * exp(-T::HALF * x * x)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_norm_cdf_f32_near_the_asymptotic_expansion_threshold() {
+    for i in 0..=128 {
+      let z = -4.0 - i as f64 / 16.0;
+      let expected = norm_cdf(z);
+      let error = fabs((norm_cdf(z as f32) as f64 - expected) / expected) / f32::EPSILON as f64;
+      // erfc(|z|/√2)  loses about  z²  ε  to the rounding of its argument in the exponential, the asymptotic expansion has to take over by  z = -6
+      let tolerance = if z <= -6.0 { 4.0 } else { 1.0 + z * z };
+      assert!(error <= tolerance, "z: {}, expected: {}, error: {}", z, expected, error);
+    }
+  }
 }
@@ -0,0 +1,106 @@
+//!
+//! Implied volatilities from strips of call and put prices without a known forward.
+//!
+//! Put-call parity for discounted prices C and P reads
+//!
+//! ```text
+//!     C(K) - P(K)  =  D·F - D·K
+//! ```
+//!
+//! so that a least-squares fit of C-P against K yields the discount factor D as minus the slope
+//! and the forward F as the intercept divided by D.
+//!
+
+use crate::definitions::*;
+use crate::lets_be_rational::*;
+use alloc::vec::Vec;
+
+/// Discounted mid prices of a call and a put with the same strike and expiry.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CallPutQuote {
+  pub strike: f64,
+  pub call: f64,
+  pub put: f64,
+}
+
+/// Forward and discount factor implied by put-call parity.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ImpliedForward {
+  pub forward: f64,
+  pub discount_factor: f64,
+}
+
+/// Implied volatility of the out-of-the-money option at one strike.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParitySmilePoint {
+  pub strike: f64,
+  /// Implied volatility, NaN when the price is flagged.
+  pub volatility: f64,
+  /// Option type that was inverted, q=±1.
+  pub q: f64,
+  /// Undiscounted price is below the intrinsic value.
+  pub below_intrinsic: bool,
+  /// Undiscounted price is at or above the maximum price, i.e. the forward for calls and the strike for puts.
+  pub above_maximum: bool,
+}
+
+/// Smile implied from a strike strip together with the implied forward and discount factor.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParitySmile {
+  pub forward: ImpliedForward,
+  pub points: Vec<ParitySmilePoint>,
+}
+
+/// Estimates the forward and the discount factor by least-squares regression on put-call parity.
+/// Returns `None` for fewer than two distinct strikes or when the fitted discount factor is not positive.
+pub fn implied_forward_from_put_call_parity(quotes: &[CallPutQuote]) -> Option<ImpliedForward> {
+  if quotes.len() < 2 {
+    return None;
+  }
+  let n = quotes.len() as f64;
+  let mean_k = quotes.iter().map(|quote| quote.strike).sum::<f64>() / n;
+  let mean_y = quotes.iter().map(|quote| quote.call - quote.put).sum::<f64>() / n;
+  let (mut s_ky, mut s_kk) = (0.0, 0.0);
+  for quote in quotes {
+    let dk = quote.strike - mean_k;
+    s_ky += dk * (quote.call - quote.put - mean_y);
+    s_kk += dk * dk;
+  }
+  if s_kk <= 0.0 {
+    return None;
+  }
+  let discount_factor = -s_ky / s_kk;
+  if discount_factor.is_nan() || discount_factor <= 0.0 {
+    return None;
+  }
+  Some(ImpliedForward {
+    forward: (mean_y + discount_factor * mean_k) / discount_factor,
+    discount_factor,
+  })
+}
+
+/// Implies the forward and discount factor from the strip and inverts the out-of-the-money option at each strike,
+/// i.e. puts for strikes below the forward and calls otherwise. `t` is the time to expiry in years.
+/// Prices outside the arbitrage bounds relative to the implied forward are flagged per strike.
+pub fn implied_volatility_smile_from_put_call_parity(quotes: &[CallPutQuote], t: f64) -> Option<ParitySmile> {
+  let forward = implied_forward_from_put_call_parity(quotes)?;
+  let mut points: Vec<ParitySmilePoint> = quotes
+    .iter()
+    .map(|quote| {
+      let q = sel(quote.strike < forward.forward, -1.0, 1.0);
+      let price = sel(q < 0.0, quote.put, quote.call) / forward.discount_factor;
+      let volatility = implied_volatility_from_a_transformed_rational_guess(price, forward.forward, quote.strike, t, q);
+      let below_intrinsic = volatility == VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC;
+      let above_maximum = volatility == VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM;
+      ParitySmilePoint {
+        strike: quote.strike,
+        volatility: sel(below_intrinsic || above_maximum, f64::NAN, volatility),
+        q,
+        below_intrinsic,
+        above_maximum,
+      }
+    })
+    .collect();
+  points.sort_by(|a, b| a.strike.total_cmp(&b.strike));
+  Some(ParitySmile { forward, points })
+}
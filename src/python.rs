@@ -0,0 +1,244 @@
+//!
+//! Python bindings, built as the extension module `impl_vol` with `maturin build --release`.
+//!
+//! All functions accept floats or array-like arguments, which are broadcast against each other
+//! following the NumPy rules. The result is a float when all arguments are scalars and an array otherwise.
+//!
+
+use crate::definitions::*;
+use crate::greeks::*;
+use crate::lets_be_rational::*;
+use numpy::ndarray::{ArrayD, ArrayViewD, IxDyn};
+use numpy::{AllowTypeChange, IntoPyArray, PyArrayLikeDyn};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyFloat};
+
+type ArrayLike<'py> = PyArrayLikeDyn<'py, f64, AllowTypeChange>;
+
+/// Returns the shape the given shapes broadcast to.
+fn broadcast_shape(shapes: &[&[usize]]) -> PyResult<Vec<usize>> {
+  let ndim = shapes.iter().map(|shape| shape.len()).max().unwrap_or(0);
+  let mut result = vec![1; ndim];
+  for shape in shapes {
+    for (target, &extent) in result[ndim - shape.len()..].iter_mut().zip(shape.iter()) {
+      if *target == 1 {
+        *target = extent;
+      } else if extent != 1 && extent != *target {
+        return Err(PyValueError::new_err(format!("shapes {:?} cannot be broadcast together", shapes)));
+      }
+    }
+  }
+  Ok(result)
+}
+
+/// Broadcasts the arguments and evaluates `function` element-wise, returning one array per output.
+fn broadcast_map<const N: usize, const M: usize>(arguments: [&ArrayLike; N], function: impl Fn([f64; N]) -> [f64; M]) -> PyResult<(Vec<usize>, [Vec<f64>; M])> {
+  let views: Vec<ArrayViewD<f64>> = arguments.iter().map(|argument| argument.as_array()).collect();
+  let shapes: Vec<&[usize]> = views.iter().map(|view| view.shape()).collect();
+  let shape = broadcast_shape(&shapes)?;
+  let broadcast: Vec<ArrayViewD<f64>> = views
+    .iter()
+    .map(|view| view.broadcast(IxDyn(&shape)).ok_or_else(|| PyValueError::new_err("broadcast failed")))
+    .collect::<PyResult<_>>()?;
+  let mut iterators: Vec<_> = broadcast.iter().map(|view| view.iter()).collect();
+  let len = shape.iter().product();
+  let mut outputs: [Vec<f64>; M] = std::array::from_fn(|_| Vec::with_capacity(len));
+  for _ in 0..len {
+    let values = std::array::from_fn(|i| *iterators[i].next().unwrap_or(&f64::NAN));
+    for (output, value) in outputs.iter_mut().zip(function(values)) {
+      output.push(value);
+    }
+  }
+  Ok((shape, outputs))
+}
+
+/// Converts an output to a float for zero-dimensional results and to an array otherwise.
+fn to_python<'py>(py: Python<'py>, shape: &[usize], values: Vec<f64>) -> PyResult<Bound<'py, PyAny>> {
+  if shape.is_empty() {
+    return Ok(PyFloat::new(py, values[0]).into_any());
+  }
+  let array = ArrayD::from_shape_vec(IxDyn(shape), values).map_err(|e| PyValueError::new_err(e.to_string()))?;
+  Ok(array.into_pyarray(py).into_any())
+}
+
+/// Undiscounted Black price of a call (q=1) or put (q=-1).
+#[pyfunction(name = "black")]
+fn py_black<'py>(py: Python<'py>, f: ArrayLike<'py>, k: ArrayLike<'py>, sigma: ArrayLike<'py>, t: ArrayLike<'py>, q: ArrayLike<'py>) -> PyResult<Bound<'py, PyAny>> {
+  let (shape, [price]) = broadcast_map([&f, &k, &sigma, &t, &q], |[f, k, sigma, t, q]| [black(f, k, sigma, t, q)])?;
+  to_python(py, &shape, price)
+}
+
+/// Implied volatility from an undiscounted price of a call (q=1) or put (q=-1).
+#[pyfunction(name = "implied_volatility_from_a_transformed_rational_guess")]
+fn py_implied_volatility_from_a_transformed_rational_guess<'py>(
+  py: Python<'py>,
+  price: ArrayLike<'py>,
+  f: ArrayLike<'py>,
+  k: ArrayLike<'py>,
+  t: ArrayLike<'py>,
+  q: ArrayLike<'py>,
+) -> PyResult<Bound<'py, PyAny>> {
+  let (shape, [volatility]) = broadcast_map([&price, &f, &k, &t, &q], |[price, f, k, t, q]| {
+    [implied_volatility_from_a_transformed_rational_guess(price, f, k, t, q)]
+  })?;
+  to_python(py, &shape, volatility)
+}
+
+/// Sensitivity of the undiscounted Black price to the volatility.
+#[pyfunction(name = "black_vega")]
+fn py_black_vega<'py>(py: Python<'py>, f: ArrayLike<'py>, k: ArrayLike<'py>, sigma: ArrayLike<'py>, t: ArrayLike<'py>) -> PyResult<Bound<'py, PyAny>> {
+  let (shape, [vega]) = broadcast_map([&f, &k, &sigma, &t], |[f, k, sigma, t]| [black_vega(f, k, sigma, t)])?;
+  to_python(py, &shape, vega)
+}
+
+/// Price and sensitivities of the undiscounted Black price, returned as a dictionary keyed by
+/// price, delta, gamma, vega, theta, vanna and volga.
+#[pyfunction(name = "black_greeks")]
+fn py_black_greeks<'py>(py: Python<'py>, f: ArrayLike<'py>, k: ArrayLike<'py>, sigma: ArrayLike<'py>, t: ArrayLike<'py>, q: ArrayLike<'py>) -> PyResult<Bound<'py, PyDict>> {
+  let (shape, outputs) = broadcast_map([&f, &k, &sigma, &t, &q], |[f, k, sigma, t, q]| {
+    let greeks = black_greeks(f, k, sigma, t, q);
+    [greeks.price, greeks.delta, greeks.gamma, greeks.vega, greeks.theta, greeks.vanna, greeks.volga]
+  })?;
+  let dict = PyDict::new(py);
+  for (name, values) in ["price", "delta", "gamma", "vega", "theta", "vanna", "volga"].into_iter().zip(outputs) {
+    dict.set_item(name, to_python(py, &shape, values)?)?;
+  }
+  Ok(dict)
+}
+
+#[pymodule(name = "impl_vol")]
+fn python_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+  m.add_function(wrap_pyfunction!(py_black, m)?)?;
+  m.add_function(wrap_pyfunction!(py_implied_volatility_from_a_transformed_rational_guess, m)?)?;
+  m.add_function(wrap_pyfunction!(py_black_vega, m)?)?;
+  m.add_function(wrap_pyfunction!(py_black_greeks, m)?)?;
+  m.add("VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC", VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC)?;
+  m.add("VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM", VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM)?;
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use numpy::{PyArray1, PyArrayMethods, PyReadonlyArrayDyn};
+  use pyo3::types::PyList;
+
+  /// Runs `test` with the module and `numpy` imported, which needs NumPy installed in the Python environment.
+  fn with_module(test: impl for<'py> FnOnce(Python<'py>, &Bound<'py, PyModule>, &Bound<'py, PyModule>) -> PyResult<()>) {
+    Python::initialize();
+    Python::attach(|py| {
+      let numpy = py.import("numpy").expect("NumPy is required by the python feature");
+      let module = PyModule::new(py, "impl_vol").unwrap();
+      python_module(&module).unwrap();
+      test(py, &module, &numpy).unwrap();
+    });
+  }
+
+  fn to_shape_and_vec(array: &Bound<'_, PyAny>) -> (Vec<usize>, Vec<f64>) {
+    let array: PyReadonlyArrayDyn<f64> = array.extract().unwrap();
+    let array = array.as_array();
+    (array.shape().to_vec(), array.iter().copied().collect())
+  }
+
+  #[test]
+  fn test_broadcast_shape() {
+    assert_eq!(Vec::<usize>::new(), broadcast_shape(&[&[], &[]]).unwrap());
+    assert_eq!(vec![3], broadcast_shape(&[&[], &[3], &[1]]).unwrap());
+    assert_eq!(vec![2, 3], broadcast_shape(&[&[2, 1], &[3], &[]]).unwrap());
+    assert_eq!(vec![4, 2, 3], broadcast_shape(&[&[4, 1, 1], &[2, 3]]).unwrap());
+    assert!(broadcast_shape(&[&[2], &[3]]).is_err());
+  }
+
+  #[test]
+  fn test_black_broadcasts_numpy_arrays() {
+    with_module(|py, module, numpy| {
+      let strikes = [90.0, 100.0, 110.0];
+      let volatilities = [0.1, 0.2];
+      let k = PyArray1::from_slice(py, &strikes);
+      let sigma = PyArray1::from_slice(py, &volatilities).reshape([2, 1])?;
+      let q = PyList::new(py, [1.0, -1.0, 1.0])?;
+      let price = module.getattr("black")?.call1((100.0, k, sigma, 0.5, q))?;
+      let (shape, values) = to_shape_and_vec(&price);
+      assert_eq!(vec![2, 3], shape);
+      for (i, sigma) in volatilities.into_iter().enumerate() {
+        for (j, (k, q)) in strikes.into_iter().zip([1.0, -1.0, 1.0]).enumerate() {
+          assert_eq!(black(100.0, k, sigma, 0.5, q), values[3 * i + j]);
+        }
+      }
+      // scalars give a float
+      let price = module.getattr("black")?.call1((100.0, 110.0, 0.2, 0.5, 1.0))?;
+      assert!(price.is_exact_instance_of::<PyFloat>());
+      assert_eq!(black(100.0, 110.0, 0.2, 0.5, 1.0), price.extract::<f64>()?);
+      // a zero-dimensional array broadcasts like a scalar
+      let t = numpy.getattr("float64")?.call1((0.5,))?;
+      let (shape, values) = to_shape_and_vec(&module.getattr("black_vega")?.call1((100.0, PyArray1::from_slice(py, &strikes), 0.2, t))?);
+      assert_eq!(vec![3], shape);
+      assert_eq!(strikes.map(|k| black_vega(100.0, k, 0.2, 0.5)).to_vec(), values);
+      Ok(())
+    });
+  }
+
+  #[test]
+  fn test_arguments_of_other_dtypes_are_converted() {
+    with_module(|py, module, numpy| {
+      let kwargs = PyDict::new(py);
+      kwargs.set_item("dtype", "float32")?;
+      let sigma = numpy.getattr("array")?.call(([0.25, 0.5],), Some(&kwargs))?;
+      // int64 strikes 100 and 101
+      let k = numpy.getattr("arange")?.call1((100, 102))?;
+      let (shape, values) = to_shape_and_vec(&module.getattr("black")?.call1((100, k, sigma, 1, 1))?);
+      assert_eq!(vec![2], shape);
+      assert_eq!(vec![black(100.0, 100.0, 0.25, 1.0, 1.0), black(100.0, 101.0, 0.5, 1.0, 1.0)], values);
+      Ok(())
+    });
+  }
+
+  #[test]
+  fn test_implied_volatility_of_numpy_arrays() {
+    with_module(|py, module, _| {
+      let (f, k, t) = (100.0, 120.0, 0.75);
+      let prices = [black(f, k, 0.3, t, 1.0), -1.0, f];
+      let volatility = module
+        .getattr("implied_volatility_from_a_transformed_rational_guess")?
+        .call1((PyArray1::from_slice(py, &prices), f, k, t, 1.0))?;
+      let (shape, values) = to_shape_and_vec(&volatility);
+      assert_eq!(vec![3], shape);
+      assert!((values[0] - 0.3).abs() < 1e-14);
+      assert_eq!(module.getattr("VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC")?.extract::<f64>()?, values[1]);
+      assert_eq!(module.getattr("VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM")?.extract::<f64>()?, values[2]);
+      Ok(())
+    });
+  }
+
+  #[test]
+  fn test_black_greeks_of_numpy_arrays() {
+    with_module(|py, module, _| {
+      let strikes = [90.0, 110.0];
+      let greeks = module.getattr("black_greeks")?.call1((100.0, PyArray1::from_slice(py, &strikes), 0.2, 0.5, -1.0))?;
+      for (i, k) in strikes.into_iter().enumerate() {
+        let expected = black_greeks(100.0, k, 0.2, 0.5, -1.0);
+        for (name, expected) in [("price", expected.price), ("delta", expected.delta), ("vega", expected.vega), ("volga", expected.volga)] {
+          let (shape, values) = to_shape_and_vec(&greeks.get_item(name)?);
+          assert_eq!(vec![2], shape);
+          assert_eq!(expected, values[i], "{}", name);
+        }
+      }
+      Ok(())
+    });
+  }
+
+  #[test]
+  fn test_arguments_that_do_not_broadcast() {
+    with_module(|py, module, _| {
+      let k = PyArray1::from_slice(py, &[90.0, 100.0]);
+      let sigma = PyArray1::from_slice(py, &[0.1, 0.2, 0.3]);
+      let error = module.getattr("black")?.call1((100.0, k, sigma, 0.5, 1.0)).unwrap_err();
+      assert!(error.is_instance_of::<PyValueError>(py));
+      assert!(error.to_string().contains("cannot be broadcast"), "{}", error);
+      // not convertible to a float array
+      assert!(module.getattr("black")?.call1((100.0, "strike", 0.2, 0.5, 1.0)).is_err());
+      Ok(())
+    });
+  }
+}
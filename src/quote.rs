@@ -0,0 +1,168 @@
+//!
+//! Strongly typed option inputs.
+//!
+//! The free functions take the option type as  q=±1  and any number as forward, strike, expiry and volatility,
+//! so that a mistyped argument silently prices something undefined. [OptionKind] and the validated newtypes
+//! rule such inputs out when an [OptionQuote] is built, and its methods wrap `black` and the implied volatility solver.
+//!
+
+use crate::definitions::*;
+use crate::lets_be_rational::*;
+
+/// Option type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OptionKind {
+  Call,
+  Put,
+}
+
+impl OptionKind {
+  /// Returns the option type as q=±1, as taken by the free functions.
+  pub fn q(&self) -> f64 {
+    match self {
+      OptionKind::Call => 1.0,
+      OptionKind::Put => -1.0,
+    }
+  }
+
+  /// Returns the option type for q=1 or q=-1, or `None` for any other value.
+  pub fn from_q(q: f64) -> Option<OptionKind> {
+    if q == 1.0 {
+      Some(OptionKind::Call)
+    } else if q == -1.0 {
+      Some(OptionKind::Put)
+    } else {
+      None
+    }
+  }
+}
+
+/// Forward, positive and finite.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(transparent))]
+pub struct Forward(f64);
+
+impl Forward {
+  /// Returns `None` unless `f` is positive and finite.
+  pub fn new(f: f64) -> Option<Forward> {
+    (f > 0.0 && f.is_finite()).then_some(Forward(f))
+  }
+
+  pub fn value(&self) -> f64 {
+    self.0
+  }
+}
+
+/// Strike, positive and finite.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(transparent))]
+pub struct Strike(f64);
+
+impl Strike {
+  /// Returns `None` unless `k` is positive and finite.
+  pub fn new(k: f64) -> Option<Strike> {
+    (k > 0.0 && k.is_finite()).then_some(Strike(k))
+  }
+
+  pub fn value(&self) -> f64 {
+    self.0
+  }
+}
+
+/// Time to expiry in years, positive and finite.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(transparent))]
+pub struct Expiry(f64);
+
+impl Expiry {
+  /// Returns `None` unless `t` is positive and finite.
+  pub fn new(t: f64) -> Option<Expiry> {
+    (t > 0.0 && t.is_finite()).then_some(Expiry(t))
+  }
+
+  pub fn value(&self) -> f64 {
+    self.0
+  }
+}
+
+/// Annualised volatility, non-negative and finite.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(transparent))]
+pub struct Volatility(f64);
+
+impl Volatility {
+  /// Returns `None` unless `sigma` is non-negative and finite.
+  pub fn new(sigma: f64) -> Option<Volatility> {
+    (sigma >= 0.0 && sigma.is_finite()).then_some(Volatility(sigma))
+  }
+
+  pub fn value(&self) -> f64 {
+    self.0
+  }
+}
+
+/// Undiscounted price of a European option together with its validated terms.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OptionQuote {
+  pub kind: OptionKind,
+  pub forward: Forward,
+  pub strike: Strike,
+  pub expiry: Expiry,
+  pub price: f64,
+}
+
+impl OptionQuote {
+  /// Returns the undiscounted Black price of the quoted option at the volatility `sigma`.
+  pub fn price(&self, sigma: Volatility) -> f64 {
+    black(self.forward.value(), self.strike.value(), sigma.value(), self.expiry.value(), self.kind.q())
+  }
+
+  /// Returns the implied volatility of the quoted price, or `None` when the price is NaN, below the intrinsic value
+  /// or at or above the maximum price, i.e. the forward for calls and the strike for puts.
+  pub fn implied_vol(&self) -> Option<Volatility> {
+    let sigma = implied_volatility_from_a_transformed_rational_guess(self.price, self.forward.value(), self.strike.value(), self.expiry.value(), self.kind.q());
+    if sigma == VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC || sigma == VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM {
+      return None;
+    }
+    Volatility::new(sigma)
+  }
+}
+
+/// Deserialisation validates like the constructors, so that a deserialised quote is as well-defined as a constructed one.
+#[cfg(feature = "serde")]
+mod validated_deserialisation {
+  use super::*;
+  use serde::de::Error;
+  use serde::{Deserialize, Deserializer};
+
+  fn validated<'de, D: Deserializer<'de>, T>(deserializer: D, new: fn(f64) -> Option<T>, expected: &str) -> Result<T, D::Error> {
+    let value = f64::deserialize(deserializer)?;
+    new(value).ok_or_else(|| D::Error::custom(format_args!("invalid value {}, expected {}", value, expected)))
+  }
+
+  impl<'de> Deserialize<'de> for Forward {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+      validated(deserializer, Forward::new, "a positive and finite forward")
+    }
+  }
+
+  impl<'de> Deserialize<'de> for Strike {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+      validated(deserializer, Strike::new, "a positive and finite strike")
+    }
+  }
+
+  impl<'de> Deserialize<'de> for Expiry {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+      validated(deserializer, Expiry::new, "a positive and finite time to expiry")
+    }
+  }
+
+  impl<'de> Deserialize<'de> for Volatility {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+      validated(deserializer, Volatility::new, "a non-negative and finite volatility")
+    }
+  }
+}
@@ -1,81 +1,88 @@
 use crate::definitions::*;
-use lazy_static::lazy_static;
+use crate::float::Float;
 
-lazy_static! {
-  pub static ref MAXIMUM_RATIONAL_CUBIC_CONTROL_PARAMETER_VALUE: f64 = 2.0 / (DBL_EPSILON * DBL_EPSILON);
-  pub static ref MINIMUM_RATIONAL_CUBIC_CONTROL_PARAMETER_VALUE: f64 = -(1.0 - DBL_EPSILON.sqrt());
+/// 2/ε²
+#[inline(always)]
+fn maximum_rational_cubic_control_parameter_value<T: Float>() -> T {
+  T::TWO / (T::EPSILON * T::EPSILON)
+}
+
+/// -(1-√ε)
+#[inline(always)]
+fn minimum_rational_cubic_control_parameter_value<T: Float>() -> T {
+  -(T::ONE - T::SQRT_EPSILON)
 }
 
 ///
 #[allow(clippy::too_many_arguments)]
-pub fn rational_cubic_interpolation(x: f64, x_l: f64, x_r: f64, y_l: f64, y_r: f64, d_l: f64, d_r: f64, r: f64) -> f64 {
+pub fn rational_cubic_interpolation<T: Float>(x: T, x_l: T, x_r: T, y_l: T, y_r: T, d_l: T, d_r: T, r: T) -> T {
   let h = x_r - x_l;
-  if fabs(h) <= 0.0 {
-    return 0.5 * (y_l + y_r);
+  if fabs(h) <= T::ZERO {
+    return T::HALF * (y_l + y_r);
   }
   // r should be greater than -1. We do not use  assert(r > -1)  here in order to allow values such as NaN to be propagated as they should.
   let t = (x - x_l) / h;
-  if r.lt(&MAXIMUM_RATIONAL_CUBIC_CONTROL_PARAMETER_VALUE) {
+  if r < maximum_rational_cubic_control_parameter_value() {
     let t = (x - x_l) / h;
-    let omt = 1.0 - t;
+    let omt = T::ONE - t;
     let t2 = t * t;
     let omt2 = omt * omt;
     // Formula (2.4) divided by formula (2.5)
-    return (y_r * t2 * t + (r * y_r - h * d_r) * t2 * omt + (r * y_l + h * d_l) * t * omt2 + y_l * omt2 * omt) / (1.0 + (r - 3.0) * t * omt);
+    return (y_r * t2 * t + (r * y_r - h * d_r) * t2 * omt + (r * y_l + h * d_l) * t * omt2 + y_l * omt2 * omt) / (T::ONE + (r - T::from_f64(3.0)) * t * omt);
   }
   // Linear interpolation without over-or underflow.
-  y_r * t + y_l * (1.0 - t)
+  y_r * t + y_l * (T::ONE - t)
 }
 
 ///
-pub fn rational_cubic_control_parameter_to_fit_second_derivative_at_left_side(x_l: f64, x_r: f64, y_l: f64, y_r: f64, d_l: f64, d_r: f64, second_derivative_l: f64) -> f64 {
+pub fn rational_cubic_control_parameter_to_fit_second_derivative_at_left_side<T: Float>(x_l: T, x_r: T, y_l: T, y_r: T, d_l: T, d_r: T, second_derivative_l: T) -> T {
   let h = x_r - x_l;
-  let numerator = 0.5 * h * second_derivative_l + (d_r - d_l);
+  let numerator = T::HALF * h * second_derivative_l + (d_r - d_l);
   if is_zero(numerator) {
-    return 0.0;
+    return T::ZERO;
   }
   let denominator = (y_r - y_l) / h - d_l;
   if is_zero(denominator) {
     return sel(
-      numerator > 0.0,
-      *MAXIMUM_RATIONAL_CUBIC_CONTROL_PARAMETER_VALUE,
-      *MINIMUM_RATIONAL_CUBIC_CONTROL_PARAMETER_VALUE,
+      numerator > T::ZERO,
+      maximum_rational_cubic_control_parameter_value(),
+      minimum_rational_cubic_control_parameter_value(),
     );
   }
   numerator / denominator
 }
 
 ///
-pub fn rational_cubic_control_parameter_to_fit_second_derivative_at_right_side(x_l: f64, x_r: f64, y_l: f64, y_r: f64, d_l: f64, d_r: f64, second_derivative_r: f64) -> f64 {
+pub fn rational_cubic_control_parameter_to_fit_second_derivative_at_right_side<T: Float>(x_l: T, x_r: T, y_l: T, y_r: T, d_l: T, d_r: T, second_derivative_r: T) -> T {
   let h = x_r - x_l;
-  let numerator = 0.5 * h * second_derivative_r + (d_r - d_l);
+  let numerator = T::HALF * h * second_derivative_r + (d_r - d_l);
   if is_zero(numerator) {
-    return 0.0;
+    return T::ZERO;
   }
   let denominator = d_r - (y_r - y_l) / h;
   if is_zero(denominator) {
     return sel(
-      numerator > 0.0,
-      *MAXIMUM_RATIONAL_CUBIC_CONTROL_PARAMETER_VALUE,
-      *MINIMUM_RATIONAL_CUBIC_CONTROL_PARAMETER_VALUE,
+      numerator > T::ZERO,
+      maximum_rational_cubic_control_parameter_value(),
+      minimum_rational_cubic_control_parameter_value(),
     );
   }
   numerator / denominator
 }
 
 ///
-pub fn minimum_rational_cubic_control_parameter(d_l: f64, d_r: f64, s: f64, prefer_shape_preservation_over_smoothness: bool) -> f64 {
-  let monotonic = d_l * s >= 0.0 && d_r * s >= 0.0;
+pub fn minimum_rational_cubic_control_parameter<T: Float>(d_l: T, d_r: T, s: T, prefer_shape_preservation_over_smoothness: bool) -> T {
+  let monotonic = d_l * s >= T::ZERO && d_r * s >= T::ZERO;
   let convex = d_l <= s && s <= d_r;
   let concave = d_l >= s && s >= d_r;
   // If 3==r_non_shape_preserving_target, this means revert to standard cubic.
   if !monotonic && !convex && !concave {
-    return *MINIMUM_RATIONAL_CUBIC_CONTROL_PARAMETER_VALUE;
+    return minimum_rational_cubic_control_parameter_value();
   }
   let d_r_m_d_l = d_r - d_l;
   let d_r_m_s = d_r - s;
   let s_m_d_l = s - d_l;
-  let mut r1 = -DBL_MAX;
+  let mut r1 = -T::MAX;
   let mut r2 = r1;
   // If monotonicity on this interval is possible, set r1 to satisfy the monotonicity condition (3.8).
   if monotonic {
@@ -84,7 +91,7 @@ pub fn minimum_rational_cubic_control_parameter(d_l: f64, d_r: f64, s: f64, pref
       r1 = (d_r + d_l) / s; // (3.8)
     } else if prefer_shape_preservation_over_smoothness {
       // If division by zero would occur, and shape preservation is preferred, set value to enforce linear interpolation.
-      r1 = *MAXIMUM_RATIONAL_CUBIC_CONTROL_PARAMETER_VALUE; // This value enforces linear interpolation.
+      r1 = maximum_rational_cubic_control_parameter_value(); // This value enforces linear interpolation.
     }
   }
   if convex || concave {
@@ -92,26 +99,26 @@ pub fn minimum_rational_cubic_control_parameter(d_l: f64, d_r: f64, s: f64, pref
       // (3.18), avoiding division by zero.
       r2 = max(fabs(d_r_m_d_l / d_r_m_s), fabs(d_r_m_d_l / s_m_d_l));
     } else if prefer_shape_preservation_over_smoothness {
-      r2 = *MAXIMUM_RATIONAL_CUBIC_CONTROL_PARAMETER_VALUE; // This value enforces linear interpolation.
+      r2 = maximum_rational_cubic_control_parameter_value(); // This value enforces linear interpolation.
     }
   } else if monotonic && prefer_shape_preservation_over_smoothness {
-    r2 = *MAXIMUM_RATIONAL_CUBIC_CONTROL_PARAMETER_VALUE; // This enforces linear interpolation along segments that are inconsistent with the slopes on the boundaries, e.g., a perfectly horizontal segment that has negative slopes on either edge.
+    r2 = maximum_rational_cubic_control_parameter_value(); // This enforces linear interpolation along segments that are inconsistent with the slopes on the boundaries, e.g., a perfectly horizontal segment that has negative slopes on either edge.
   }
-  max(*MINIMUM_RATIONAL_CUBIC_CONTROL_PARAMETER_VALUE, max(r1, r2))
+  max(minimum_rational_cubic_control_parameter_value(), max(r1, r2))
 }
 
 ///
 #[allow(clippy::too_many_arguments)]
-pub fn convex_rational_cubic_control_parameter_to_fit_second_derivative_at_left_side(
-  x_l: f64,
-  x_r: f64,
-  y_l: f64,
-  y_r: f64,
-  d_l: f64,
-  d_r: f64,
-  second_derivative_l: f64,
+pub fn convex_rational_cubic_control_parameter_to_fit_second_derivative_at_left_side<T: Float>(
+  x_l: T,
+  x_r: T,
+  y_l: T,
+  y_r: T,
+  d_l: T,
+  d_r: T,
+  second_derivative_l: T,
   prefer_shape_preservation_over_smoothness: bool,
-) -> f64 {
+) -> T {
   let r = rational_cubic_control_parameter_to_fit_second_derivative_at_left_side(x_l, x_r, y_l, y_r, d_l, d_r, second_derivative_l);
   let r_min = minimum_rational_cubic_control_parameter(d_l, d_r, (y_r - y_l) / (x_r - x_l), prefer_shape_preservation_over_smoothness);
   max(r, r_min)
@@ -119,16 +126,16 @@ pub fn convex_rational_cubic_control_parameter_to_fit_second_derivative_at_left_
 
 ///
 #[allow(clippy::too_many_arguments)]
-pub fn convex_rational_cubic_control_parameter_to_fit_second_derivative_at_right_side(
-  x_l: f64,
-  x_r: f64,
-  y_l: f64,
-  y_r: f64,
-  d_l: f64,
-  d_r: f64,
-  second_derivative_r: f64,
+pub fn convex_rational_cubic_control_parameter_to_fit_second_derivative_at_right_side<T: Float>(
+  x_l: T,
+  x_r: T,
+  y_l: T,
+  y_r: T,
+  d_l: T,
+  d_r: T,
+  second_derivative_r: T,
   prefer_shape_preservation_over_smoothness: bool,
-) -> f64 {
+) -> T {
   let r = rational_cubic_control_parameter_to_fit_second_derivative_at_right_side(x_l, x_r, y_l, y_r, d_l, d_r, second_derivative_r);
   let r_min = minimum_rational_cubic_control_parameter(d_l, d_r, (y_r - y_l) / (x_r - x_l), prefer_shape_preservation_over_smoothness);
   max(r, r_min)
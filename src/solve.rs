@@ -0,0 +1,95 @@
+//!
+//! Implied volatilities together with the solver configuration and diagnostics they were obtained with.
+//!
+//! The implied volatility functions without explicit iteration limit depend on process-wide settings. A [SolveResult]
+//! records them in a [SolverConfig], so that a persisted result can be replayed bit for bit.
+//!
+
+use crate::definitions::*;
+use crate::lets_be_rational::*;
+use crate::quote::OptionQuote;
+
+/// Process-wide settings of the implied volatility solver.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SolverConfig {
+  /// Maximum number of iterations.
+  pub maximum_iterations: usize,
+  /// Order of the Householder method: 4 for Householder(3), 3 for Halley and 2 for Newton.
+  /// The process-wide order is fixed to 4 without the `ENABLE_CHANGING_THE_HOUSEHOLDER_METHOD_ORDER` feature.
+  pub householder_method_order: usize,
+}
+
+impl Default for SolverConfig {
+  fn default() -> Self {
+    Self {
+      maximum_iterations: 2,
+      householder_method_order: 4,
+    }
+  }
+}
+
+impl SolverConfig {
+  /// Returns the settings currently in effect.
+  pub fn current() -> SolverConfig {
+    SolverConfig {
+      maximum_iterations: get_implied_volatility_maximum_iterations(),
+      householder_method_order: implied_volatility_householder_method_order(),
+    }
+  }
+}
+
+/// Implied volatility of a quote with the settings and the number of iterations it was obtained with.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SolveResult {
+  pub quote: OptionQuote,
+  pub config: SolverConfig,
+  /// Implied volatility, or one of the signal values for prices outside the bounds.
+  pub volatility: f64,
+  pub iterations: usize,
+}
+
+impl SolveResult {
+  /// Returns `true` when the price is below the intrinsic value.
+  pub fn is_below_intrinsic(&self) -> bool {
+    self.volatility == VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC
+  }
+
+  /// Returns `true` when the price is at or above the maximum price, i.e. the forward for calls and the strike for puts.
+  pub fn is_above_maximum(&self) -> bool {
+    self.volatility == VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM
+  }
+
+  /// Inverts the quote again with the recorded settings, which equals the recorded volatility bit for bit.
+  pub fn replay(&self) -> f64 {
+    self.quote.solve_with(self.config).volatility
+  }
+}
+
+impl OptionQuote {
+  /// Inverts the quoted price with the settings currently in effect, recording them with the number of iterations.
+  pub fn solve(&self) -> SolveResult {
+    self.solve_with(SolverConfig::current())
+  }
+
+  /// Inverts the quoted price with the given settings instead of the ones in effect, which are left untouched.
+  pub fn solve_with(&self, config: SolverConfig) -> SolveResult {
+    let (volatility, iterations) = implied_volatility_and_iteration_count(
+      self.price,
+      self.forward.value(),
+      self.strike.value(),
+      self.expiry.value(),
+      self.kind.q(),
+      None,
+      config.maximum_iterations,
+      config.householder_method_order,
+    );
+    SolveResult {
+      quote: *self,
+      config,
+      volatility,
+      iterations,
+    }
+  }
+}
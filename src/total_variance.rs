@@ -0,0 +1,62 @@
+//!
+//! Black prices and implied volatilities in terms of the total variance and the log-moneyness.
+//!
+//! Volatility surfaces are commonly parametrised by
+//!
+//! ```text
+//!     total variance     w  =  σ²·T
+//!     log-moneyness      k  =  ln(K/F)
+//! ```
+//!
+//! and so are the functions of this module: **their argument `k` is the log-moneyness, not the strike** as elsewhere in
+//! this crate. The normalised functions take the total volatility  s = σ·√T = √w  and  x = ln(F/K) = -k,  and normalise
+//! prices by  √(F·K).  The functions of this module take and return total variances, so that the time to expiry does
+//! not enter and zero expiry is not a special case. The forward normalised variants quote prices in units of the forward.
+//!
+
+use crate::definitions::*;
+use crate::float::Float;
+use crate::lets_be_rational::*;
+
+/// Total variance of the total volatility `s`, keeping the signal values.
+fn total_variance<T: Float>(s: T) -> T {
+  sel(fabs(s) == T::MAX, s, s * s)
+}
+
+/// Undiscounted Black price of a call (q=1) or put (q=-1) for the forward `f`, log-moneyness `k` = ln(K/F) and total
+/// variance `w` ≥ 0.
+pub fn black_from_total_variance<T: Float>(f: T, k: T, w: T, q: T /* q=±1 */) -> T {
+  f * forward_normalised_black_from_total_variance(k, w, q)
+}
+
+/// Implied total variance from the undiscounted price of a call (q=1) or put (q=-1) for the forward `f` and log-moneyness
+/// `k` = ln(K/F).
+///
+/// Prices below the intrinsic value give [VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC], prices at or above
+/// the maximum price give [VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM]. NaN arguments give NaN.
+pub fn implied_total_variance<T: Float>(price: T, f: T, k: T, q: T /* q=±1 */) -> T {
+  implied_total_variance_from_forward_normalised_price(price / f, k, q)
+}
+
+/// Undiscounted Black price in units of the forward, i.e. for F=1, of a call (q=1) or put (q=-1) for the log-moneyness
+/// `k` = ln(K/F) and total variance `w` ≥ 0.
+pub fn forward_normalised_black_from_total_variance<T: Float>(k: T, w: T, q: T /* q=±1 */) -> T {
+  exp(T::HALF * k) * normalised_black(-k, sqrt(w), q)
+}
+
+/// Implied total variance from the undiscounted price `c` in units of the forward of a call (q=1) or put (q=-1)
+/// for the log-moneyness `k` = ln(K/F).
+///
+/// Prices below the intrinsic value give [VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC], prices at or above
+/// the maximum price give [VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM]. NaN arguments give NaN.
+pub fn implied_total_variance_from_forward_normalised_price<T: Float>(c: T, k: T, q: T /* q=±1 */) -> T {
+  let (s, iterations) = normalised_implied_volatility_and_iteration_count(
+    c * exp(-T::HALF * k),
+    -k,
+    q,
+    None,
+    get_implied_volatility_maximum_iterations(),
+    implied_volatility_householder_method_order(),
+  );
+  implied_volatility_output(iterations, total_variance(s))
+}
@@ -0,0 +1,77 @@
+//!
+//! WebAssembly bindings for JavaScript.
+//!
+//! Batch functions take `Float64Array` arguments of equal length; arguments of length one are used for every element.
+//!
+
+use crate::lets_be_rational::*;
+use wasm_bindgen::prelude::*;
+
+/// Returns the common length of the arguments, treating arguments of length one as scalars.
+fn batch_len(arguments: &[&[f64]]) -> Result<usize, JsError> {
+  let len = arguments.iter().map(|argument| argument.len()).filter(|&len| len != 1).max().unwrap_or(1);
+  if arguments.iter().any(|argument| argument.len() != 1 && argument.len() != len) {
+    return Err(JsError::new("arguments must have equal length or length one"));
+  }
+  Ok(len)
+}
+
+/// Evaluates `function` element-wise over the batch arguments.
+fn batch<const N: usize>(arguments: [&[f64]; N], function: impl Fn([f64; N]) -> f64) -> Result<Vec<f64>, JsError> {
+  let len = batch_len(&arguments)?;
+  Ok(
+    (0..len)
+      .map(|i| function(arguments.map(|argument| argument[if argument.len() == 1 { 0 } else { i }])))
+      .collect(),
+  )
+}
+
+/// Undiscounted Black price of a call (q=1) or put (q=-1).
+#[wasm_bindgen(js_name = black)]
+pub fn wasm_black(f: f64, k: f64, sigma: f64, t: f64, q: f64) -> f64 {
+  black(f, k, sigma, t, q)
+}
+
+/// Normalised Black price of a call (q=1) or put (q=-1) for  x = ln(F/K)  and  s = σ·√T.
+#[wasm_bindgen(js_name = normalisedBlack)]
+pub fn wasm_normalised_black(x: f64, s: f64, q: f64) -> f64 {
+  normalised_black(x, s, q)
+}
+
+/// Implied volatility from an undiscounted price of a call (q=1) or put (q=-1).
+#[wasm_bindgen(js_name = impliedVolatility)]
+pub fn wasm_implied_volatility(price: f64, f: f64, k: f64, t: f64, q: f64) -> f64 {
+  implied_volatility_from_a_transformed_rational_guess(price, f, k, t, q)
+}
+
+/// Implied total volatility  s = σ·√T  from a normalised price.
+#[wasm_bindgen(js_name = normalisedImpliedVolatility)]
+pub fn wasm_normalised_implied_volatility(beta: f64, x: f64, q: f64) -> f64 {
+  normalised_implied_volatility_from_a_transformed_rational_guess(beta, x, q)
+}
+
+/// Undiscounted Black prices for arrays of inputs.
+#[wasm_bindgen(js_name = blackBatch)]
+pub fn wasm_black_batch(f: &[f64], k: &[f64], sigma: &[f64], t: &[f64], q: &[f64]) -> Result<Vec<f64>, JsError> {
+  batch([f, k, sigma, t, q], |[f, k, sigma, t, q]| black(f, k, sigma, t, q))
+}
+
+/// Implied volatilities for arrays of inputs.
+#[wasm_bindgen(js_name = impliedVolatilityBatch)]
+pub fn wasm_implied_volatility_batch(price: &[f64], f: &[f64], k: &[f64], t: &[f64], q: &[f64]) -> Result<Vec<f64>, JsError> {
+  batch([price, f, k, t, q], |[price, f, k, t, q]| {
+    implied_volatility_from_a_transformed_rational_guess(price, f, k, t, q)
+  })
+}
+
+/// Value returned by the implied volatility functions when the price is below the intrinsic value.
+#[wasm_bindgen(js_name = volatilityValueToSignalPriceIsBelowIntrinsic)]
+pub fn wasm_volatility_value_to_signal_price_is_below_intrinsic() -> f64 {
+  crate::definitions::VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC
+}
+
+/// Value returned by the implied volatility functions when the price is at or above the maximum price.
+#[wasm_bindgen(js_name = volatilityValueToSignalPriceIsAboveMaximum)]
+pub fn wasm_volatility_value_to_signal_price_is_above_maximum() -> f64 {
+  crate::definitions::VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM
+}
@@ -0,0 +1,119 @@
+#![cfg(feature = "alloc")]
+
+use impl_vol::*;
+
+fn grid() -> Vec<CallPriceQuote> {
+  let mut quotes = vec![];
+  for expiry in [0.25, 0.5, 1.0] {
+    for strike in [80.0, 90.0, 100.0, 110.0, 120.0] {
+      quotes.push(CallPriceQuote {
+        strike,
+        expiry,
+        forward: 100.0,
+        price: black(100.0, strike, 0.2, expiry, 1.0),
+      });
+    }
+  }
+  quotes
+}
+
+#[test]
+fn test_arbitrage_free_grid() {
+  assert!(check_static_arbitrage(&grid(), 1e-12).is_empty());
+}
+
+#[test]
+fn test_bounds_violations() {
+  let mut quotes = grid();
+  quotes[0].price = 19.0;
+  quotes[14].price = 100.5;
+  let violations = check_static_arbitrage(&quotes, 1e-12);
+  assert!(violations.contains(&ArbitrageViolation::BelowIntrinsic { index: 0 }));
+  assert!(violations.contains(&ArbitrageViolation::AboveMaximum { index: 14 }));
+  // the bounds respect the tolerance like the other checks
+  quotes[0].price = 19.5;
+  quotes[14].price = 100.5;
+  let violations = check_static_arbitrage(&quotes, 1.0);
+  assert!(!violations.contains(&ArbitrageViolation::BelowIntrinsic { index: 0 }));
+  assert!(!violations.contains(&ArbitrageViolation::AboveMaximum { index: 14 }));
+  assert!(check_static_arbitrage(&quotes, 0.4).contains(&ArbitrageViolation::AboveMaximum { index: 14 }));
+}
+
+#[test]
+fn test_strike_violations() {
+  let mut quotes = grid();
+  quotes[8].price = quotes[7].price + 0.5;
+  let violations = check_static_arbitrage(&quotes, 1e-12);
+  assert!(violations.contains(&ArbitrageViolation::StrikeMonotonicity { lower: 7, higher: 8 }));
+  assert!(violations.contains(&ArbitrageViolation::StrikeConvexity { left: 7, middle: 8, right: 9 }));
+}
+
+#[test]
+fn test_calendar_violation() {
+  let mut quotes = grid();
+  quotes[7].price = black(100.0, 100.0, 0.1, 0.5, 1.0);
+  let violations = check_static_arbitrage(&quotes, 1e-12);
+  assert!(violations.contains(&ArbitrageViolation::CalendarMonotonicity { earlier: 2, later: 7 }));
+  assert!(!violations.contains(&ArbitrageViolation::CalendarMonotonicity { earlier: 7, later: 12 }));
+}
+
+#[test]
+fn test_unsorted_grid() {
+  let mut quotes = grid();
+  quotes.reverse();
+  assert!(check_static_arbitrage(&quotes, 1e-12).is_empty());
+}
+
+/// Grid with a forward per expiry, so that strikes of different expiries never share their moneyness.
+fn grid_with_forward_curve() -> Vec<CallPriceQuote> {
+  let mut quotes = vec![];
+  for expiry in [0.25, 0.5, 1.0] {
+    let forward = 100.0 * (0.03 * expiry).exp();
+    for i in 0..=40 {
+      let strike = 80.0 + i as f64;
+      quotes.push(CallPriceQuote {
+        strike,
+        expiry,
+        forward,
+        price: black(forward, strike, 0.2, expiry, 1.0),
+      });
+    }
+  }
+  quotes
+}
+
+#[test]
+fn test_calendar_violation_with_forward_curve() {
+  let mut quotes = grid_with_forward_curve();
+  assert!(check_static_arbitrage(&quotes, 1e-12).is_empty());
+  // the one year price at the strike 98 drops below the earlier ones, which are interpolated at its moneyness between the strikes 95 and 96, and 96 and 97
+  let (forward, strike) = (quotes[100].forward, quotes[100].strike);
+  quotes[100].price = black(forward, strike, 0.05, 1.0, 1.0);
+  let violations = check_static_arbitrage(&quotes, 1e-12);
+  let calendar: Vec<_> = violations.iter().filter(|v| matches!(v, ArbitrageViolation::CalendarMonotonicity { .. })).collect();
+  assert!(calendar.contains(&&ArbitrageViolation::CalendarMonotonicity { earlier: 16, later: 100 }), "{:?}", calendar);
+  assert!(calendar.contains(&&ArbitrageViolation::CalendarMonotonicity { earlier: 58, later: 100 }), "{:?}", calendar);
+}
+
+#[test]
+fn test_dense_arbitrage_free_grid_with_smile_and_forward_curve() {
+  // the total variance  w(y, T) = σ(y)²·T  in the log-moneyness  y = ln(K/F)  increases with the expiry, interpolating the prices
+  // linearly in K/F reported calendar violations between the nearby expiries even at this strike spacing
+  let volatility = |y: f64| 0.2 - 0.1 * y + 0.3 * y * y;
+  let mut quotes = vec![];
+  for expiry in [7.0 / 365.0, 8.0 / 365.0, 14.0 / 365.0, 0.25, 0.26, 1.0, 2.0] {
+    let forward = 100.0 * (0.05 * expiry).exp();
+    for i in 0..=400 {
+      let strike = 60.0 + 0.2 * i as f64;
+      quotes.push(CallPriceQuote {
+        strike,
+        expiry,
+        forward,
+        price: black(forward, strike, volatility((strike / forward).ln()), expiry, 1.0),
+      });
+    }
+  }
+  let violations = check_static_arbitrage(&quotes, 0.0);
+  let calendar: Vec<_> = violations.iter().filter(|v| matches!(v, ArbitrageViolation::CalendarMonotonicity { .. })).collect();
+  assert!(calendar.is_empty(), "{} calendar violations, e.g. {:?}", calendar.len(), calendar.first());
+}
@@ -0,0 +1,49 @@
+use impl_vol::*;
+
+fn close(expected: f64, actual: f64, tolerance: f64) {
+  assert!((expected - actual).abs() < tolerance, "expected: {}\n  actual: {},", expected, actual);
+}
+
+#[test]
+fn test_bid_ask_implied_volatility() {
+  let (f, k, t) = (2104.57774868891, 2150.0, 0.25);
+  let bid = black(f, k, 0.19, t, 1.0);
+  let ask = black(f, k, 0.21, t, 1.0);
+  let result = bid_ask_implied_volatility(bid, ask, f, k, t, 1.0);
+  close(0.19, result.bid_volatility, 1e-13);
+  close(0.21, result.ask_volatility, 1e-13);
+  assert!(result.mid_volatility > 0.19 && result.mid_volatility < 0.21);
+  close(0.02, result.volatility_spread(), 1e-13);
+  assert!(!result.bid_below_intrinsic && !result.ask_above_maximum && !result.crossed);
+  assert!(result.is_clean(0.05));
+  assert!(!result.is_clean(0.01));
+}
+
+#[test]
+fn test_bid_ask_implied_volatility_vega_weighting() {
+  // Vega grows with volatility for this out-of-the-money call, pulling the mid towards the ask.
+  let (f, k, t) = (100.0, 130.0, 0.5);
+  let result = bid_ask_implied_volatility(black(f, k, 0.2, t, 1.0), black(f, k, 0.3, t, 1.0), f, k, t, 1.0);
+  assert!(result.mid_volatility > 0.25 && result.mid_volatility < 0.3);
+}
+
+#[test]
+fn test_bid_ask_implied_volatility_flags() {
+  let (f, k, t) = (100.0, 90.0, 0.5);
+  let ask = black(f, k, 0.25, t, 1.0);
+  let result = bid_ask_implied_volatility(9.5, ask, f, k, t, 1.0);
+  assert!(result.bid_below_intrinsic);
+  assert_eq!(0.0, result.bid_volatility);
+  close(0.25, result.mid_volatility, 1e-13);
+  assert!(!result.is_clean(f64::MAX));
+
+  let bid = black(f, k, 0.25, t, -1.0);
+  let result = bid_ask_implied_volatility(bid, 95.0, f, k, t, -1.0);
+  assert!(result.ask_above_maximum);
+  assert_eq!(f64::INFINITY, result.ask_volatility);
+  close(0.25, result.mid_volatility, 1e-13);
+
+  let result = bid_ask_implied_volatility(ask + 0.1, ask, f, k, t, 1.0);
+  assert!(result.crossed);
+  assert!(!result.is_clean(f64::MAX));
+}
@@ -0,0 +1,130 @@
+#![cfg(feature = "std")]
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn impl_vol(args: &[&str], input: &str) -> Output {
+  let mut child = Command::new(env!("CARGO_BIN_EXE_impl-vol"))
+    .args(args)
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .stderr(Stdio::piped())
+    .spawn()
+    .unwrap();
+  // The tool may exit on invalid arguments before reading its input.
+  let _ = child.stdin.take().unwrap().write_all(input.as_bytes());
+  child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> Vec<String> {
+  String::from_utf8(output.stdout.clone()).unwrap().lines().map(String::from).collect()
+}
+
+#[test]
+fn test_invert_csv() {
+  let input = "\
+price,forward,strike,expiry,type
+539.1269453050334,2170.4221251767294,1700.00,0.926027,call
+21.306793271589243,2133.480231762442,1500.00,0.427397,put
+1.0,100,50,1,c
+2.0,100,50,1,p
+101.0,100,50,1,call
+x,100,50,1,c
+1.0,-100,50,1,c
+";
+  let output = impl_vol(&[], input);
+  assert!(output.status.success());
+  let lines = stdout(&output);
+  assert_eq!("price,forward,strike,expiry,type,implied_volatility,status,iterations", lines[0]);
+  assert!(lines[1].ends_with(",0.3148253556850184,ok,2"), "{}", lines[1]);
+  assert!(lines[2].ends_with(",0.41134905571706226,ok,2"), "{}", lines[2]);
+  assert!(lines[3].ends_with(",,below_intrinsic,0"), "{}", lines[3]);
+  assert!(lines[4].contains(",ok,"), "{}", lines[4]);
+  assert!(lines[5].ends_with(",,above_maximum,0"), "{}", lines[5]);
+  assert!(lines[6].ends_with(",,invalid_input,"), "{}", lines[6]);
+  assert!(lines[7].ends_with(",,invalid_input,"), "{}", lines[7]);
+}
+
+#[test]
+fn test_invert_csv_with_column_mapping() {
+  let input = "\
+premium;F;K;T;cp
+539.1269453050334;2170.4221251767294;1700.00;0.926027;1
+";
+  let args = [
+    "-d",
+    ";",
+    "--price-column",
+    "premium",
+    "--forward-column",
+    "F",
+    "--strike-column",
+    "K",
+    "--expiry-column",
+    "T",
+    "--type-column",
+    "cp",
+  ];
+  let output = impl_vol(&args, input);
+  assert!(output.status.success());
+  assert_eq!("539.1269453050334;2170.4221251767294;1700.00;0.926027;1;0.3148253556850184;ok;2", stdout(&output)[1]);
+}
+
+#[test]
+fn test_invert_csv_errors() {
+  let output = impl_vol(&[], "price,forward,strike,expiry\n1,2,3,4\n");
+  assert_eq!(Some(1), output.status.code());
+  assert!(String::from_utf8_lossy(&output.stderr).contains("column 'type' not found"));
+  let output = impl_vol(&["--unknown"], "");
+  assert_eq!(Some(2), output.status.code());
+}
+
+const VOLATILITIES: &str = "\
+volatility,forward,strike,expiry,type
+0.3148253556850184,2170.4221251767294,1700.00,0.926027,call
+0.25,100,120,0.5,p
+";
+
+#[test]
+fn test_price() {
+  let output = impl_vol(&["price"], VOLATILITIES);
+  assert!(output.status.success());
+  let lines = stdout(&output);
+  assert_eq!("volatility,forward,strike,expiry,type,price", lines[0]);
+  let price: f64 = lines[1].rsplit(',').next().unwrap().parse().unwrap();
+  assert!((price - 539.1269453050334).abs() < 1e-9, "{}", price);
+}
+
+#[test]
+fn test_greeks_json() {
+  let output = impl_vol(&["greeks", "--format", "json"], VOLATILITIES);
+  assert!(output.status.success());
+  let text = String::from_utf8(output.stdout).unwrap();
+  assert!(text.starts_with("[\n  {\"volatility\": 0.3148253556850184, \"forward\": 2170.4221251767294, \"strike\": 1700, \"expiry\": 0.926027, \"type\": \"call\", \"price\": "));
+  assert!(text.contains("\"type\": \"p\""));
+  assert!(text.contains("\"volga\": "));
+  assert!(text.ends_with("}\n]\n"));
+  assert_eq!(4, text.lines().count());
+}
+
+#[test]
+fn test_roundtrip_table() {
+  let output = impl_vol(&["roundtrip", "-f", "table"], VOLATILITIES);
+  assert!(output.status.success());
+  let lines = stdout(&output);
+  assert_eq!(3, lines.len());
+  assert!(lines[0].trim_start().starts_with("volatility  "));
+  assert!(lines[0].ends_with("relative_error  status"));
+  assert!(lines.iter().all(|line| line.len() == lines[0].len()));
+  for line in &lines[1..] {
+    assert!(line.ends_with("  ok"));
+    let relative_error: f64 = line.split_whitespace().rev().nth(1).unwrap().parse().unwrap();
+    assert!(relative_error.abs() < 1e-14);
+  }
+}
+
+#[test]
+fn test_unknown_format() {
+  let output = impl_vol(&["price", "-f", "xml"], VOLATILITIES);
+  assert_eq!(Some(2), output.status.code());
+}
@@ -0,0 +1,71 @@
+use impl_vol::*;
+
+const CONVENTIONS: [DeltaConvention; 4] = [
+  DeltaConvention::Forward,
+  DeltaConvention::Spot,
+  DeltaConvention::PremiumAdjustedForward,
+  DeltaConvention::PremiumAdjustedSpot,
+];
+
+fn close(expected: f64, actual: f64, tolerance: f64) {
+  assert!((expected - actual).abs() < tolerance, "expected: {}\n  actual: {},", expected, actual);
+}
+
+#[test]
+fn test_strike_from_delta_round_trip() {
+  let (f, df_foreign) = (1.3125, 0.97);
+  for convention in CONVENTIONS {
+    for (sigma, t) in [(0.05, 0.02), (0.12, 0.5), (0.35, 2.0), (0.6, 1.5)] {
+      for delta in [0.01, 0.05, 0.1, 0.25, 0.35] {
+        let call = strike_from_delta(delta, f, sigma, t, 1.0, df_foreign, convention);
+        let put = strike_from_delta(-delta, f, sigma, t, -1.0, df_foreign, convention);
+        close(delta, black_delta(f, call, sigma, t, 1.0, df_foreign, convention), 1e-12);
+        close(-delta, black_delta(f, put, sigma, t, -1.0, df_foreign, convention), 1e-12);
+        assert!(put < call, "{:?} put strike {} not below call strike {}", convention, put, call);
+      }
+    }
+  }
+}
+
+#[test]
+fn test_strike_from_forward_delta_closed_form() {
+  let (f, sigma, t) = (100.0, 0.2, 1.0);
+  let k = strike_from_delta(0.5, f, sigma, t, 1.0, 1.0, DeltaConvention::Forward);
+  close(f * (0.5 * sigma * sigma * t).exp(), k, 1e-12);
+  let k = strike_from_delta(-0.5, f, sigma, t, -1.0, 1.0, DeltaConvention::Forward);
+  close(f * (0.5 * sigma * sigma * t).exp(), k, 1e-12);
+}
+
+#[test]
+fn test_strike_from_premium_adjusted_call_delta_right_of_maximum() {
+  let (f, sigma, t) = (1.0, 0.5, 4.0);
+  let k = strike_from_delta(0.3, f, sigma, t, 1.0, 1.0, DeltaConvention::PremiumAdjustedForward);
+  // The same delta is attained left of the maximum at a lower strike, which must not be chosen.
+  let slope = black_delta(f, k * 1.0001, sigma, t, 1.0, 1.0, DeltaConvention::PremiumAdjustedForward) - 0.3;
+  assert!(slope < 0.0);
+}
+
+#[test]
+fn test_strike_from_premium_adjusted_put_delta_beyond_one() {
+  let (f, sigma, t, df_foreign) = (1.0, 0.5, 4.0, 0.97);
+  for convention in [DeltaConvention::PremiumAdjustedForward, DeltaConvention::PremiumAdjustedSpot] {
+    for delta in [-0.99, -1.0, -1.2, -3.0] {
+      let k = strike_from_delta(delta, f, sigma, t, -1.0, df_foreign, convention);
+      assert!(k > f, "{:?} strike {} for delta {}", convention, k, delta);
+      close(delta, black_delta(f, k, sigma, t, -1.0, df_foreign, convention), 1e-12);
+    }
+  }
+  // without premium adjustment, put deltas are bounded by one
+  assert!(strike_from_delta(-1.2, f, sigma, t, -1.0, 1.0, DeltaConvention::Forward).is_nan());
+}
+
+#[test]
+fn test_strike_from_delta_unattainable() {
+  assert!(strike_from_delta(0.0, 1.0, 0.2, 1.0, 1.0, 1.0, DeltaConvention::Forward).is_nan());
+  assert!(strike_from_delta(1.0, 1.0, 0.2, 1.0, 1.0, 1.0, DeltaConvention::Forward).is_nan());
+  assert!(strike_from_delta(0.25, 1.0, 0.2, 1.0, -1.0, 1.0, DeltaConvention::Forward).is_nan());
+  assert!(strike_from_delta(0.98, 1.0, 0.2, 1.0, 1.0, 0.98, DeltaConvention::Spot).is_nan());
+  assert!(strike_from_delta(0.25, 1.0, 0.0, 1.0, 1.0, 1.0, DeltaConvention::Forward).is_nan());
+  // The premium-adjusted call delta is bounded well below one for large total volatility.
+  assert!(strike_from_delta(0.6, 1.0, 1.0, 4.0, 1.0, 1.0, DeltaConvention::PremiumAdjustedForward).is_nan());
+}
@@ -0,0 +1,61 @@
+use impl_vol::*;
+
+fn close(expected: f64, actual: f64, tolerance: f64) {
+  assert!((expected - actual).abs() <= tolerance * expected.abs(), "expected: {}\n  actual: {},", expected, actual);
+}
+
+#[test]
+fn test_implied_volatility_from_discounted_price() {
+  let (f, t) = (2170.4221251767294, 0.926027);
+  for df in [0.97, 1.02, 1e-3] {
+    for k in [1700.0, 2170.0, 2700.0] {
+      for q in [1.0, -1.0] {
+        let premium = discounted_black(f, k, 0.25, t, q, df);
+        close(df * black(f, k, 0.25, t, q), premium, 1e-15);
+        close(0.25, implied_volatility_from_discounted_price(premium, f, k, t, q, df), 1e-13);
+      }
+    }
+  }
+  let (r, premium) = (0.035, discounted_black(f, 2300.0, 0.3, t, -1.0, (-0.035 * t).exp()));
+  close(0.3, implied_volatility_from_discounted_price_and_rate(premium, f, 2300.0, t, -1.0, r), 1e-13);
+}
+
+#[test]
+fn test_implied_volatility_from_discounted_price_bounds() {
+  let (f, k, t, df) = (100.0, 90.0, 0.5, 0.9);
+  assert_eq!(
+    VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC,
+    implied_volatility_from_discounted_price(8.9, f, k, t, 1.0, df)
+  );
+  assert_eq!(
+    VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM,
+    implied_volatility_from_discounted_price(90.0, f, k, t, 1.0, df)
+  );
+  assert_eq!(
+    VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM,
+    implied_volatility_from_discounted_price(81.0, f, k, t, -1.0, df)
+  );
+  // undiscounted, the same premium would be within the bounds
+  assert_eq!(
+    VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM,
+    implied_volatility_from_discounted_price(95.0, f, k, t, 1.0, df)
+  );
+  assert_eq!(0.0, implied_volatility_from_discounted_price(0.0, f, k, t, -1.0, df));
+  for df in [0.0, -0.9, f64::INFINITY, f64::NAN] {
+    assert!(implied_volatility_from_discounted_price(10.0, f, k, t, 1.0, df).is_nan());
+  }
+  assert!(implied_volatility_from_discounted_price(-1.0, f64::NAN, k, t, 1.0, df).is_nan());
+}
+
+#[test]
+fn test_implied_volatility_from_discounted_price_at_intrinsic() {
+  // 8.0003/0.80003 rounds below 10, dividing first would signal a price below the intrinsic value
+  let (f, k, t, df) = (100.0, 90.0, 0.5, 0.80003);
+  let premium = df * (f - k);
+  assert!(premium / df < f - k);
+  assert_eq!(
+    VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC,
+    implied_volatility_from_a_transformed_rational_guess(premium / df, f, k, t, 1.0)
+  );
+  assert_eq!(0.0, implied_volatility_from_discounted_price(premium, f, k, t, 1.0, df));
+}
@@ -0,0 +1,43 @@
+#![cfg(feature = "ffi")]
+
+use impl_vol::ffi::*;
+use std::ptr;
+
+#[test]
+fn test_scalar_functions() {
+  assert_eq!(impl_vol::black(100.0, 110.0, 0.2, 1.0, -1.0), impl_vol_black(100.0, 110.0, 0.2, 1.0, -1.0));
+  assert_eq!(impl_vol::erfcx_cody(3.5), impl_vol_erfcx_cody(3.5));
+  assert_eq!(
+    0.3148253556850184,
+    impl_vol_implied_volatility_from_a_transformed_rational_guess(539.1269453050334, 2170.4221251767294, 1700.00, 0.926027, 1.0)
+  );
+  assert_eq!(
+    IMPL_VOL_VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC,
+    impl_vol_implied_volatility_from_a_transformed_rational_guess(1.0, 100.0, 50.0, 1.0, 1.0)
+  );
+  assert_eq!(
+    impl_vol::VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC,
+    IMPL_VOL_VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC
+  );
+  assert_eq!(
+    impl_vol::VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM,
+    IMPL_VOL_VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM
+  );
+}
+
+#[test]
+fn test_batch() {
+  let price = [539.1269453050334, 21.306793271589243];
+  let f = [2170.4221251767294, 2133.480231762442];
+  let k = [1700.00, 1500.00];
+  let t = [0.926027, 0.427397];
+  let q = [1.0, -1.0];
+  let mut volatility = [0.0; 2];
+  let status = unsafe { impl_vol_implied_volatility_batch(price.as_ptr(), f.as_ptr(), k.as_ptr(), t.as_ptr(), q.as_ptr(), 2, volatility.as_mut_ptr()) };
+  assert_eq!(IMPL_VOL_OK, status);
+  assert_eq!([0.3148253556850184, 0.41134905571706226], volatility);
+  let status = unsafe { impl_vol_implied_volatility_batch(price.as_ptr(), ptr::null(), k.as_ptr(), t.as_ptr(), q.as_ptr(), 2, volatility.as_mut_ptr()) };
+  assert_eq!(IMPL_VOL_NULL_POINTER, status);
+  let status = unsafe { impl_vol_implied_volatility_batch(ptr::null(), ptr::null(), ptr::null(), ptr::null(), ptr::null(), 0, ptr::null_mut()) };
+  assert_eq!(IMPL_VOL_OK, status);
+}
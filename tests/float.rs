@@ -0,0 +1,123 @@
+use impl_vol::*;
+
+/// Relative difference of an `f32` result from the `f64` result, in units of `f32::EPSILON`.
+fn f32_error(expected: f64, actual: f32) -> f64 {
+  ((actual as f64 - expected) / expected).abs() / f32::EPSILON as f64
+}
+
+#[test]
+fn test_normalised_black_f32_matches_f64() {
+  // asymptotic expansion, small-t expansion, erfcx and norm_cdf branches
+  for (x, s) in [(-2.2, 0.2), (-0.01, 0.05), (-0.1, 0.5), (-2.0, 2.5), (0.3, 0.8), (0.0, 1.0)] {
+    for q in [-1.0, 1.0] {
+      let expected = normalised_black(x, s, q);
+      let actual = normalised_black(x as f32, s as f32, q as f32);
+      assert!(
+        f32_error(expected, actual) < 64.0,
+        "x: {}, s: {}, q: {}, expected: {}, actual: {}",
+        x,
+        s,
+        q,
+        expected,
+        actual
+      );
+    }
+  }
+}
+
+/// Relative error bound of the `f32` price, in units of `f32::EPSILON`. The asymptotic expansion is accurate to a few  ε  and has to take over
+/// by  h = x/s = -6.5,  the other branches lose up to four powers of  h  to the cancellation in  a = 1+h·Y(h)  and in the coefficients of the
+/// small-t expansion.
+fn f32_tolerance(x: f64, s: f64) -> f64 {
+  let tau = 2.0 * f32::SIXTEENTH_ROOT_EPSILON as f64;
+  if x < -6.5 * s && 0.5 * s * s + x < s * (tau - 6.5) {
+    4.0
+  } else {
+    4.0 * (1.0 + (x / s).powi(4))
+  }
+}
+
+#[test]
+fn test_normalised_black_f32_near_the_asymptotic_expansion_threshold() {
+  // h = x/s  from -4 to -12 across  η = -6.5,  and  t = s/2  up to beyond  τ ≈ 0.74,  on dyadic values exact in both types
+  for i in 0..=128 {
+    let h = -4.0 - i as f64 / 16.0;
+    for j in 1..=24 {
+      let s = j as f64 / 16.0;
+      let x = h * s;
+      let (expected, actual) = (normalised_black_call(x, s), normalised_black_call(x as f32, s as f32));
+      assert!(
+        f32_error(expected, actual) <= f32_tolerance(x, s),
+        "h: {}, s: {}, expected: {}, actual: {}",
+        h,
+        s,
+        expected,
+        actual
+      );
+    }
+  }
+}
+
+#[test]
+fn test_normalised_black_f32_near_the_small_t_expansion_threshold() {
+  // s  across  2τ ≈ 1.477
+  for x in [-0.0625, -0.25, -0.5, -1.0, -2.0, -3.0] {
+    for j in 40..=56 {
+      let s = j as f64 / 32.0;
+      let (expected, actual) = (normalised_black_call(x, s), normalised_black_call(x as f32, s as f32));
+      assert!(
+        f32_error(expected, actual) <= f32_tolerance(x, s),
+        "x: {}, s: {}, expected: {}, actual: {}",
+        x,
+        s,
+        expected,
+        actual
+      );
+    }
+  }
+}
+
+#[test]
+fn test_implied_volatility_f32_round_trip() {
+  for (f, k, t) in [(100.0_f32, 100.0, 1.0), (100.0, 80.0, 0.25), (100.0, 130.0, 2.0), (2170.42, 1700.0, 0.926027)] {
+    // in the money the time value of low volatilities is lost in the intrinsic value, use the out-of-the-money option
+    let q = if k < f { -1.0 } else { 1.0 };
+    for sigma in [0.05_f32, 0.2, 0.8] {
+      let price = black(f, k, sigma, t, q);
+      let implied = implied_volatility_from_a_transformed_rational_guess(price, f, k, t, q);
+      assert!(
+        ((implied - sigma) / sigma).abs() < 1e-5,
+        "f: {}, k: {}, t: {}, sigma: {}, q: {}, implied: {}",
+        f,
+        k,
+        t,
+        sigma,
+        q,
+        implied
+      );
+    }
+  }
+}
+
+#[test]
+fn test_implied_volatility_f32_signals_arbitrage() {
+  assert_eq!(-f32::MAX, implied_volatility_from_a_transformed_rational_guess(10.0_f32, 120.0, 100.0, 1.0, 1.0));
+  assert_eq!(f32::MAX, implied_volatility_from_a_transformed_rational_guess(130.0_f32, 120.0, 100.0, 1.0, 1.0));
+}
+
+#[test]
+fn test_erf_cody_f32_matches_f64() {
+  for x in [-9.0, -3.0, -0.4, 0.0, 1e-5, 0.3, 0.47, 1.2, 4.0, 9.0] {
+    assert!((erf_cody(x as f32) as f64 - erf_cody(x)).abs() < 4.0 * f32::EPSILON as f64, "erf({})", x);
+    assert!(f32_error(erfc_cody(x), erfc_cody(x as f32)) < 8.0, "erfc({})", x);
+    assert!(f32_error(erfcx_cody(x), erfcx_cody(x as f32)) < 8.0, "erfcx({})", x);
+  }
+}
+
+#[test]
+fn test_f64_is_unchanged_by_generic_instantiation() {
+  fn generic<T: Float>(price: T, f: T, k: T, t: T, q: T) -> T {
+    implied_volatility_from_a_transformed_rational_guess(price, f, k, t, q)
+  }
+  assert_eq!(0.3148253556850184, generic(539.1269453050334, 2170.4221251767294, 1700.00, 0.926027, 1.0));
+}
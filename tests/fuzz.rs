@@ -0,0 +1,64 @@
+//!
+//! Regression tests for the inputs found by the fuzz targets under `fuzz/`, named after the failing input.
+//!
+//! The solver fixes that go with them: the signal values are returned unscaled, the middle-branch Householder
+//! correction is evaluated in  h = x/s  instead of  x/s²,  and NaN arguments give NaN.
+//!
+
+use impl_vol::*;
+
+/// `fuzz/artifacts/implied_volatility/crash-5fb6e1682e541effa443bc61cdec63bd498e137d`: the price rounds onto the maximum
+/// after normalisation, and the signal value overflowed to infinity when divided by √t.
+#[test]
+fn test_implied_volatility_crash_5fb6e168_signal_value_is_not_scaled() {
+  let (price, f, k, t, q, n) = (9.499192897581168e-42, 9.49919289814537e-42, 1.1210844976560816e273, 0.17188262939453125, 1.0, 32);
+  assert_eq!(
+    VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM,
+    implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(price, f, k, t, q, n)
+  );
+}
+
+/// At the money with a denormalised price,  s²  underflows to zero and the Householder correction turned into 0/0.
+#[test]
+fn test_implied_volatility_at_the_money_with_a_denormalised_price() {
+  for (price, f, t, q) in [
+    (5e-324, 1.0, 0.5503918407136477, -1.0),
+    (8.238005380478342e-169, 1.0, 5.410028579734718, -1.0),
+    (3.4078854854405183, f64::MAX, 8.234609847683771, 1.0),
+    (4.871781686737861, f64::MAX, 1.7372848246370275e285, 1.0),
+  ] {
+    let sigma = implied_volatility_from_a_transformed_rational_guess(price, f, f, t, q);
+    assert!(sigma.is_finite() && sigma >= 0.0, "price: {}, f: {}, t: {}, sigma: {}", price, f, t, sigma);
+    // b(0, s) ≈ s/√(2π)  for tiny  s,  to within a few units of the smallest denormalised number
+    let expected = (2.0 * std::f64::consts::PI).sqrt() * (price / f) / t.sqrt();
+    assert!(
+      (sigma - expected).abs() <= 1e-12 * expected + 4.0 * 5e-324,
+      "expected: {:e}\n  actual: {:e},",
+      expected,
+      sigma
+    );
+  }
+}
+
+#[test]
+fn test_nan_arguments_give_nan() {
+  let (price, f, k, t, q, sigma) = (10.0, 100.0, 110.0, 0.5, 1.0, 0.2);
+  assert!(black(f64::NAN, k, sigma, t, q).is_nan());
+  assert!(black(f, f64::NAN, sigma, t, q).is_nan());
+  assert!(black(f, k, f64::NAN, t, q).is_nan());
+  assert!(black(f, k, sigma, f64::NAN, q).is_nan());
+  assert!(black(f, k, sigma, t, f64::NAN).is_nan());
+  assert!(normalised_black(f64::NAN, sigma, q).is_nan());
+  assert!(normalised_black(-0.1, sigma, f64::NAN).is_nan());
+  assert!(normalised_black_call(-0.1, f64::NAN).is_nan());
+  for n in [0, 2, 32] {
+    assert!(implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(f64::NAN, f, k, t, q, n).is_nan());
+    assert!(implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(price, f64::NAN, k, t, q, n).is_nan());
+    assert!(implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(price, f, f64::NAN, t, q, n).is_nan());
+    assert!(implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(price, f, k, f64::NAN, q, n).is_nan());
+    assert!(implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(price, f, k, t, f64::NAN, n).is_nan());
+    assert!(normalised_implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(f64::NAN, -0.1, q, n).is_nan());
+    assert!(normalised_implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(0.01, f64::NAN, q, n).is_nan());
+    assert!(normalised_implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(0.01, -0.1, f64::NAN, n).is_nan());
+  }
+}
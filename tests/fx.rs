@@ -0,0 +1,118 @@
+#![cfg(feature = "alloc")]
+
+use impl_vol::*;
+
+const CONVENTIONS: [DeltaConvention; 4] = [
+  DeltaConvention::Forward,
+  DeltaConvention::Spot,
+  DeltaConvention::PremiumAdjustedForward,
+  DeltaConvention::PremiumAdjustedSpot,
+];
+
+fn close(expected: f64, actual: f64, tolerance: f64) {
+  assert!((expected - actual).abs() < tolerance, "expected: {}\n  actual: {},", expected, actual);
+}
+
+fn quote() -> FxMarketQuote {
+  FxMarketQuote {
+    atm_volatility: 0.105,
+    wings: vec![
+      FxWingQuote {
+        delta: 0.25,
+        risk_reversal: -0.012,
+        butterfly: 0.0025,
+      },
+      FxWingQuote {
+        delta: 0.10,
+        risk_reversal: -0.025,
+        butterfly: 0.008,
+      },
+    ],
+  }
+}
+
+#[test]
+fn test_fx_smile_strikes_match_quoted_deltas() {
+  let (f, t, df_foreign) = (1.0875, 0.5, 0.985);
+  for convention in CONVENTIONS {
+    let smile = fx_smile_from_market_quote(&quote(), f, t, df_foreign, convention, AtmConvention::DeltaNeutralStraddle).unwrap();
+    assert_eq!(5, smile.len());
+    assert!(smile.windows(2).all(|w| w[0].strike < w[1].strike));
+    for point in &smile {
+      match point.pillar {
+        FxPillar::Call(delta) => close(delta, black_delta(f, point.strike, point.volatility, t, 1.0, df_foreign, convention), 1e-12),
+        FxPillar::Put(delta) => close(-delta, black_delta(f, point.strike, point.volatility, t, -1.0, df_foreign, convention), 1e-12),
+        FxPillar::Atm => {
+          let call = black_delta(f, point.strike, point.volatility, t, 1.0, df_foreign, convention);
+          let put = black_delta(f, point.strike, point.volatility, t, -1.0, df_foreign, convention);
+          close(0.0, call + put, 1e-12);
+        }
+      }
+    }
+  }
+}
+
+#[test]
+fn test_fx_market_quote_round_trip_through_prices() {
+  let (f, t, df_foreign) = (151.2, 0.25, 0.99);
+  for convention in CONVENTIONS {
+    let smile = fx_smile_from_market_quote(&quote(), f, t, df_foreign, convention, AtmConvention::Forward).unwrap();
+    let repriced: Vec<FxSmilePoint> = smile.iter().map(|point| fx_smile_point_from_price(point.pillar, point.price, f, point.strike, t)).collect();
+    let actual = fx_market_quote_from_smile(&repriced).unwrap();
+    let expected = quote();
+    close(expected.atm_volatility, actual.atm_volatility, 1e-13);
+    assert_eq!(expected.wings.len(), actual.wings.len());
+    for (expected, actual) in expected.wings.iter().zip(actual.wings.iter()) {
+      assert_eq!(expected.delta, actual.delta);
+      close(expected.risk_reversal, actual.risk_reversal, 1e-13);
+      close(expected.butterfly, actual.butterfly, 1e-13);
+    }
+  }
+}
+
+#[test]
+fn test_fx_market_quote_requires_atm_and_matching_wings() {
+  let smile = fx_smile_from_market_quote(&quote(), 1.0, 1.0, 1.0, DeltaConvention::Forward, AtmConvention::Forward).unwrap();
+  let without_atm: Vec<FxSmilePoint> = smile.iter().copied().filter(|point| point.pillar != FxPillar::Atm).collect();
+  assert!(fx_market_quote_from_smile(&without_atm).is_none());
+  let without_put: Vec<FxSmilePoint> = smile.iter().copied().filter(|point| point.pillar != FxPillar::Put(0.25)).collect();
+  assert!(fx_market_quote_from_smile(&without_put).is_none());
+}
+
+#[test]
+fn test_atm_strike() {
+  close(1.25, atm_strike(1.25, 0.1, 2.0, DeltaConvention::Spot, AtmConvention::Forward), 1e-15);
+  close(
+    1.25 * 0.01_f64.exp(),
+    atm_strike(1.25, 0.1, 2.0, DeltaConvention::Spot, AtmConvention::DeltaNeutralStraddle),
+    1e-15,
+  );
+  close(
+    1.25 * (-0.01_f64).exp(),
+    atm_strike(1.25, 0.1, 2.0, DeltaConvention::PremiumAdjustedSpot, AtmConvention::DeltaNeutralStraddle),
+    1e-15,
+  );
+}
+
+#[test]
+fn test_fx_smile_with_unreachable_wings() {
+  let quote = |atm_volatility: f64, risk_reversal: f64| FxMarketQuote {
+    atm_volatility,
+    wings: vec![FxWingQuote {
+      delta: 0.25,
+      risk_reversal,
+      butterfly: 0.01,
+    }],
+  };
+  // at high total variance, the 25-delta call lies above the maximum of the premium-adjusted call delta
+  let (f, t, df_foreign) = (1.0, 10.0, 0.9);
+  for convention in CONVENTIONS {
+    let smile = fx_smile_from_market_quote(&quote(0.5, 0.02), f, t, df_foreign, convention, AtmConvention::Forward);
+    assert_eq!(!convention.is_premium_adjusted(), smile.is_some(), "{:?}", convention);
+    assert!(smile.is_none_or(|smile| smile.iter().all(|point| point.strike > 0.0 && point.price.is_finite())));
+  }
+  // a negative call wing volatility
+  for convention in CONVENTIONS {
+    assert!(fx_smile_from_market_quote(&quote(0.05, -0.2), f, 1.0, df_foreign, convention, AtmConvention::Forward).is_none());
+  }
+}
@@ -0,0 +1,38 @@
+use impl_vol::*;
+
+fn close(expected: f64, actual: f64, tolerance: f64) {
+  assert!(
+    (expected - actual).abs() < tolerance * expected.abs().max(1.0),
+    "expected: {}\n  actual: {},",
+    expected,
+    actual
+  );
+}
+
+#[test]
+fn test_black_greeks_against_finite_differences() {
+  for (f, k, sigma, t, q) in [(100.0, 90.0, 0.2, 1.0, 1.0), (100.0, 120.0, 0.35, 0.25, -1.0), (2104.5, 2150.0, 0.3, 0.00274, 1.0)] {
+    let greeks = black_greeks(f, k, sigma, t, q);
+    let (hf, hs, ht) = (1e-6 * f, 1e-6, 1e-6 * t);
+    let price = |f: f64, sigma: f64, t: f64| black(f, k, sigma, t, q);
+    close(price(f, sigma, t), greeks.price, 1e-15);
+    close((price(f + hf, sigma, t) - price(f - hf, sigma, t)) / (2.0 * hf), greeks.delta, 1e-6);
+    close(
+      (price(f + hf, sigma, t) - 2.0 * price(f, sigma, t) + price(f - hf, sigma, t)) / (hf * hf),
+      greeks.gamma,
+      1e-4,
+    );
+    close((price(f, sigma + hs, t) - price(f, sigma - hs, t)) / (2.0 * hs), greeks.vega, 1e-6);
+    close(-(price(f, sigma, t + ht) - price(f, sigma, t - ht)) / (2.0 * ht), greeks.theta, 1e-6);
+    close(
+      (price(f + hf, sigma + hs, t) - price(f + hf, sigma - hs, t) - price(f - hf, sigma + hs, t) + price(f - hf, sigma - hs, t)) / (4.0 * hf * hs),
+      greeks.vanna,
+      1e-4,
+    );
+    close(
+      (price(f, sigma + hs, t) - 2.0 * price(f, sigma, t) + price(f, sigma - hs, t)) / (hs * hs),
+      greeks.volga,
+      1e-3,
+    );
+  }
+}
@@ -0,0 +1,72 @@
+use impl_vol::*;
+
+const ONE_OVER_SQRT_TWO_PI: f64 = 0.3989422804014327;
+
+fn norm_pdf(z: f64) -> f64 {
+  ONE_OVER_SQRT_TWO_PI * (-0.5 * z * z).exp()
+}
+
+/// Undiscounted Bachelier price of a call (q=1) or put (q=-1) with the normal volatility `sigma`.
+fn bachelier(f: f64, k: f64, sigma: f64, t: f64, q: f64) -> f64 {
+  let s = sigma * t.sqrt();
+  let d = q * (f - k) / s;
+  q * (f - k) * 0.5 * erfc_cody(-d / std::f64::consts::SQRT_2) + s * norm_pdf(d)
+}
+
+#[test]
+fn test_safeguarded_householder_root_inverts_bachelier_prices() {
+  let (f, t) = (0.03, 2.0);
+  for k in [0.02, 0.03, 0.045] {
+    for sigma in [0.005, 0.01, 0.03] {
+      for q in [1.0, -1.0] {
+        let price = bachelier(f, k, sigma, t, q);
+        // The price is increasing in the volatility with  ∂p/∂σ = √t·φ(d),  ∂²p/∂σ² = √t·φ(d)·d²/σ  and  ∂³p/∂σ³ = √t·φ(d)·(d⁴-3d²)/σ².
+        let objective = |sigma: f64| {
+          let d = (f - k) / (sigma * t.sqrt());
+          let vega = t.sqrt() * norm_pdf(d);
+          HouseholderEvaluation::from_derivatives(
+            bachelier(f, k, sigma, t, q) - price,
+            vega,
+            vega * d * d / sigma,
+            vega * (d * d * d * d - 3.0 * d * d) / (sigma * sigma),
+          )
+        };
+        for order in [2, 3, 4] {
+          let (root, iterations) = safeguarded_householder_root(objective, 0.01, 0.0, f64::MAX, order, 100);
+          assert!((root - sigma).abs() <= 1e-12 * sigma, "k: {}, sigma: {}, order: {}, root: {}", k, sigma, order, root);
+          assert!(iterations < 100);
+        }
+      }
+    }
+  }
+}
+
+#[test]
+fn test_safeguarded_householder_root_order() {
+  // s³ = 2
+  let objective = |s: f64| HouseholderEvaluation::from_derivatives(s * s * s - 2.0, 3.0 * s * s, 6.0 * s, 6.0);
+  let mut iterations = [0; 3];
+  for order in [2, 3, 4] {
+    let (root, count) = safeguarded_householder_root(objective, 3.0, 0.0, 4.0, order, 100);
+    assert!((root - 2.0_f64.cbrt()).abs() <= 2.0 * f64::EPSILON);
+    iterations[order - 2] = count;
+  }
+  assert!(iterations[0] > iterations[1] && iterations[1] >= iterations[2], "iterations: {:?}", iterations);
+  // a decreasing objective function passes its negative
+  let objective = |s: f64| HouseholderEvaluation {
+    g: s * s * s - 2.0,
+    ..HouseholderEvaluation::from_derivatives(1.0 / (s * s * s) - 0.5, -3.0 / (s * s * s * s), 12.0 / (s * s * s * s * s), -60.0 / (s * s * s * s * s * s))
+  };
+  let (root, _) = safeguarded_householder_root(objective, 1.5, 0.0, 4.0, 4, 100);
+  assert!((root - 2.0_f64.cbrt()).abs() <= 2.0 * f64::EPSILON);
+}
+
+#[test]
+fn test_safeguarded_householder_root_bisects_without_ratios() {
+  let objective = |s: f64| HouseholderEvaluation { g: s * s - 2.0, ratios: None };
+  let (root, iterations) = safeguarded_householder_root(objective, 1.0, 0.5, 3.0, 4, 200);
+  assert!((root - 2.0_f64.sqrt()).abs() <= 4.0 * f64::EPSILON);
+  assert!(iterations > 40 && iterations < 200);
+  // the iteration count is limited
+  assert_eq!(10, safeguarded_householder_root(objective, 1.0, 0.5, 3.0, 4, 10).1);
+}
@@ -1,3 +1,6 @@
+// The reference values are those of the std math functions, tests/libm.rs compares the libm build against them.
+#![cfg(feature = "std")]
+
 use impl_vol::*;
 
 fn eq(expected: f64, actual: f64) {
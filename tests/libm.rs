@@ -0,0 +1,56 @@
+// Compares the implied volatilities of the build with the libm math functions against the reference values of the std
+// build in tests/implied_volatility.rs.
+#![cfg(not(feature = "std"))]
+
+use impl_vol::*;
+
+/// Arguments of `eq(expected, f(price, forward, strike, expiry, q));` lines.
+fn cases(source: &str) -> Vec<[f64; 6]> {
+  source
+    .lines()
+    .filter_map(|line| line.trim().strip_prefix("eq(")?.strip_suffix("));"))
+    .map(|arguments| {
+      let values: Vec<f64> = arguments.split([',', '(']).filter_map(|value| value.trim().parse().ok()).collect();
+      values.try_into().unwrap()
+    })
+    .collect()
+}
+
+fn ulps(expected: f64, actual: f64) -> u64 {
+  (expected.to_bits() as i64 - actual.to_bits() as i64).unsigned_abs()
+}
+
+/// Φ(z)
+fn norm_cdf(z: f64) -> f64 {
+  0.5 * erfc_cody(-z * std::f64::consts::FRAC_1_SQRT_2)
+}
+
+/// Bound of the difference in units in the last place between the implied volatilities of the two builds.
+///
+/// Both exp and ln are faithfully rounded in std and libm, i.e. their results differ by at most one unit in the last place,
+/// and sqrt is correctly rounded in both. The solver finds  s = σ·√T  from  b(x, s) = β  for the out-of-the-money price
+/// b(x, s) = Φ(h+t)·exp(x/2) - Φ(h-t)·exp(-x/2),  h = x/s,  t = s/2,  x ≤ 0,  in which each of the two terms involves at most two
+/// exponentials, one of them within erfc or erfcx. This changes  b  by up to  2ε·(A+B)  with the terms  A  and  B,  whose sum
+/// is  2·∂b/∂x,  and the rounding of  x = ln(F/K)  changes it by  ε·|x|·∂b/∂x.  Divided by the vega  ∂b/∂s,  the relative
+/// difference of  s  is at most  ε·(4+|x|)·∂b/∂x/(s·∂b/∂s),  which is at most twice as many units in the last place of  σ,
+/// plus one for the last Householder step of each build.
+fn ulps_bound(f: f64, k: f64, t: f64, sigma: f64) -> u64 {
+  let (x, s) = (-(f / k).ln().abs(), sigma * t.sqrt());
+  let db_dx = 0.5 * (norm_cdf(x / s + 0.5 * s) * (0.5 * x).exp() + norm_cdf(x / s - 0.5 * s) * (-0.5 * x).exp());
+  (2.0 * (4.0 + x.abs()) * db_dx / (s * normalised_vega(x, s)) + 2.0).ceil() as u64
+}
+
+#[test]
+fn test_implied_volatility_with_libm_against_std() {
+  let cases = cases(include_str!("implied_volatility.rs"));
+  assert!(cases.len() > 250);
+  let mut differences = Vec::new();
+  for [expected, price, f, k, t, q] in cases {
+    let actual = implied_volatility_from_a_transformed_rational_guess(price, f, k, t, q);
+    let bound = ulps_bound(f, k, t, expected);
+    if ulps(expected, actual) > bound {
+      differences.push((expected, actual, ulps(expected, actual), bound));
+    }
+  }
+  assert!(differences.is_empty(), "(std, libm, ulps, bound): {:?}", differences);
+}
@@ -0,0 +1,82 @@
+use impl_vol::*;
+
+fn close(expected: f64, actual: f64, tolerance: f64) {
+  assert!((expected - actual).abs() <= tolerance * expected.abs(), "expected: {}\n  actual: {},", expected, actual);
+}
+
+#[test]
+fn test_ln_normalised_black_call_matches_normalised_black_call() {
+  for x in [-0.5, -2.0, -13.6, -89.2, -394.4, -699.6, 0.0, 0.3] {
+    for s in [0.05, 0.5, 2.75, 12.5, 23.5] {
+      let b = normalised_black_call(x, s);
+      if b > 1e-300 {
+        close(b.ln(), ln_normalised_black_call(x, s), 4.0 * f64::EPSILON);
+      }
+    }
+  }
+  assert!(ln_normalised_black_call(f64::NAN, 1.0).is_nan());
+  assert_eq!(f64::NEG_INFINITY, ln_normalised_black_call(-1.0, 0.0));
+}
+
+#[test]
+fn test_ln_normalised_black_call_below_smallest_positive_number() {
+  // b(0, s) = 2·Φ(s/2) - 1 ≈ s/√(2π)
+  for s in [1e-200, 1e-320] {
+    assert!(normalised_black_call(0.0, s) < 1e-199);
+    close(s.ln() - (2.0 * std::f64::consts::PI).sqrt().ln(), ln_normalised_black_call(0.0, s), 2.0 * f64::EPSILON);
+  }
+  // below the underflow of b(x, s), ln(b) keeps increasing with s
+  for x in [-1.0, -100.0, -3000.0] {
+    let mut previous = f64::NEG_INFINITY;
+    for i in 1..200 {
+      let s = i as f64 * (2.0 * -x).sqrt() / 200.0;
+      let ln_b = ln_normalised_black_call(x, s);
+      assert!(ln_b.is_finite() && ln_b > previous, "x: {}, s: {}, ln(b): {}", x, s, ln_b);
+      previous = ln_b;
+    }
+  }
+}
+
+#[test]
+fn test_normalised_implied_volatility_from_ln_price() {
+  for (x, s) in [(-5.5, 0.06), (-20.5, 0.15), (-45.5, 1.0), (-490.5, 13.86), (-900.5, 38.01), (-3000.0, 77.0), (-1.0, 1e-3)] {
+    let ln_beta = ln_normalised_black_call(x, s);
+    assert!(ln_beta < -360.0);
+    close(s, normalised_implied_volatility_from_ln_price(ln_beta, x, 1.0), 8.0 * f64::EPSILON);
+    close(s, normalised_implied_volatility_from_ln_price(ln_beta, -x, -1.0), 8.0 * f64::EPSILON);
+  }
+  // at the money, ln(b) ≈ ln(s) - ln(√(2π)) only resolves s to the relative accuracy  ε·|ln(b)|
+  let ln_beta = ln_normalised_black_call(0.0, 1e-200);
+  close(1e-200, normalised_implied_volatility_from_ln_price(ln_beta, 0.0, 1.0), 2.0 * f64::EPSILON * ln_beta.abs());
+  // prices above √DBL_MIN are passed on
+  for (x, s, q) in [(-0.5, 0.2, 1.0), (0.5, 0.2, 1.0), (-0.5, 0.2, -1.0), (-10.0, 0.8, 1.0)] {
+    let beta = normalised_black(x, s, q);
+    assert_eq!(
+      normalised_implied_volatility_from_a_transformed_rational_guess(beta, x, q),
+      normalised_implied_volatility_from_ln_price(beta.ln(), x, q)
+    );
+  }
+  assert_eq!(
+    VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM,
+    normalised_implied_volatility_from_ln_price(-1000.0, -2000.0, 1.0)
+  );
+  assert_eq!(0.0, normalised_implied_volatility_from_ln_price(f64::NEG_INFINITY, -2000.0, 1.0));
+  assert!(normalised_implied_volatility_from_ln_price(f64::NAN, -2000.0, 1.0).is_nan());
+}
+
+#[test]
+fn test_implied_volatility_from_ln_price() {
+  let (f, t) = (2170.4221251767294, 0.926027);
+  for (k, q) in [(2e7, 1.0), (1e-2, -1.0), (1700.0, 1.0), (1700.0, -1.0)] {
+    for sigma in [0.1, 0.5] {
+      let x = (f / k).ln();
+      let ln_price = 0.5 * (f.ln() + k.ln()) + ln_normalised_black_call(q * x, sigma * t.sqrt());
+      close(sigma, implied_volatility_from_ln_price(ln_price, f, k, t, q), 1e-13);
+    }
+  }
+  assert_eq!(VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM, implied_volatility_from_ln_price(f.ln(), f, 2e7, t, 1.0));
+  assert_eq!(
+    VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC,
+    implied_volatility_from_ln_price(400.0_f64.ln(), f, 1700.0, t, 1.0)
+  );
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "alloc")]
+
+use impl_vol::*;
+
+fn close(expected: f64, actual: f64, tolerance: f64) {
+  assert!((expected - actual).abs() < tolerance, "expected: {}\n  actual: {},", expected, actual);
+}
+
+fn volatility(k: f64) -> f64 {
+  0.2 + 0.1 * (k / 2000.0 - 1.0).powi(2) - 0.05 * (k / 2000.0 - 1.0)
+}
+
+fn strip(f: f64, d: f64, t: f64) -> Vec<CallPutQuote> {
+  (0..21)
+    .map(|i| {
+      let strike = 1500.0 + 50.0 * i as f64;
+      CallPutQuote {
+        strike,
+        call: d * black(f, strike, volatility(strike), t, 1.0),
+        put: d * black(f, strike, volatility(strike), t, -1.0),
+      }
+    })
+    .collect()
+}
+
+#[test]
+fn test_implied_forward_from_put_call_parity() {
+  let forward = implied_forward_from_put_call_parity(&strip(2010.5, 0.987, 0.75)).unwrap();
+  close(2010.5, forward.forward, 1e-9);
+  close(0.987, forward.discount_factor, 1e-13);
+}
+
+#[test]
+fn test_implied_volatility_smile_from_put_call_parity() {
+  let smile = implied_volatility_smile_from_put_call_parity(&strip(2010.5, 0.987, 0.75), 0.75).unwrap();
+  assert_eq!(21, smile.points.len());
+  for point in &smile.points {
+    assert_eq!(if point.strike < 2010.5 { -1.0 } else { 1.0 }, point.q);
+    assert!(!point.below_intrinsic && !point.above_maximum);
+    close(volatility(point.strike), point.volatility, 1e-10);
+  }
+}
+
+#[test]
+fn test_implied_volatility_smile_flags_prices_outside_the_bounds() {
+  let (f, d, t) = (2010.5, 0.987, 0.75);
+  let mut quotes = strip(f, d, t);
+  // a negative put and a call worth the forward, both consistent with put-call parity
+  quotes[0].put = -0.01 * d;
+  quotes[0].call = quotes[0].put + d * (f - quotes[0].strike);
+  quotes[20].call = d * f;
+  quotes[20].put = quotes[20].call - d * (f - quotes[20].strike);
+  let smile = implied_volatility_smile_from_put_call_parity(&quotes, t).unwrap();
+  close(f, smile.forward.forward, 1e-9);
+  for (i, point) in smile.points.iter().enumerate() {
+    assert_eq!(i == 0, point.below_intrinsic);
+    assert_eq!(i == 20, point.above_maximum);
+    if i == 0 || i == 20 {
+      assert!(point.volatility.is_nan());
+    } else {
+      close(volatility(point.strike), point.volatility, 1e-10);
+    }
+  }
+}
+
+#[test]
+fn test_implied_forward_requires_distinct_strikes() {
+  let quote = CallPutQuote {
+    strike: 100.0,
+    call: 5.0,
+    put: 4.0,
+  };
+  assert!(implied_forward_from_put_call_parity(&[]).is_none());
+  assert!(implied_forward_from_put_call_parity(&[quote]).is_none());
+  assert!(implied_forward_from_put_call_parity(&[quote, quote]).is_none());
+}
@@ -0,0 +1,62 @@
+use impl_vol::*;
+
+fn close(expected: f64, actual: f64, tolerance: f64) {
+  assert!((expected - actual).abs() < tolerance, "expected: {}\n  actual: {},", expected, actual);
+}
+
+fn quote(kind: OptionKind, f: f64, k: f64, t: f64, price: f64) -> OptionQuote {
+  OptionQuote {
+    kind,
+    forward: Forward::new(f).unwrap(),
+    strike: Strike::new(k).unwrap(),
+    expiry: Expiry::new(t).unwrap(),
+    price,
+  }
+}
+
+#[test]
+fn test_option_kind() {
+  assert_eq!(1.0, OptionKind::Call.q());
+  assert_eq!(-1.0, OptionKind::Put.q());
+  assert_eq!(Some(OptionKind::Call), OptionKind::from_q(1.0));
+  assert_eq!(Some(OptionKind::Put), OptionKind::from_q(-1.0));
+  for q in [0.0, 2.0, -0.5, f64::NAN, f64::INFINITY] {
+    assert_eq!(None, OptionKind::from_q(q));
+  }
+}
+
+#[test]
+fn test_validated_inputs() {
+  for x in [0.0, -1.0, f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+    assert!(Forward::new(x).is_none());
+    assert!(Strike::new(x).is_none());
+    assert!(Expiry::new(x).is_none());
+  }
+  for x in [-1.0, f64::NAN, f64::INFINITY] {
+    assert!(Volatility::new(x).is_none());
+  }
+  assert_eq!(0.0, Volatility::new(0.0).unwrap().value());
+  assert_eq!(2170.4221251767294, Forward::new(2170.4221251767294).unwrap().value());
+}
+
+#[test]
+fn test_option_quote_round_trip() {
+  let (f, k, t) = (2170.4221251767294, 1900.0, 0.926027);
+  for kind in [OptionKind::Call, OptionKind::Put] {
+    let price = quote(kind, f, k, t, 0.0).price(Volatility::new(0.25).unwrap());
+    assert_eq!(black(f, k, 0.25, t, kind.q()), price);
+    let sigma = quote(kind, f, k, t, price).implied_vol().unwrap();
+    assert_eq!(implied_volatility_from_a_transformed_rational_guess(price, f, k, t, kind.q()), sigma.value());
+    close(0.25, sigma.value(), 1e-14);
+  }
+}
+
+#[test]
+fn test_option_quote_outside_bounds() {
+  let (f, k, t) = (100.0, 90.0, 0.5);
+  assert!(quote(OptionKind::Call, f, k, t, 9.5).implied_vol().is_none());
+  assert!(quote(OptionKind::Call, f, k, t, 100.0).implied_vol().is_none());
+  assert!(quote(OptionKind::Put, f, k, t, 90.0).implied_vol().is_none());
+  assert!(quote(OptionKind::Put, f, k, t, f64::NAN).implied_vol().is_none());
+  assert_eq!(Some(0.0), quote(OptionKind::Put, f, k, t, 0.0).implied_vol().map(|sigma| sigma.value()));
+}
@@ -0,0 +1,54 @@
+use impl_vol::*;
+
+#[test]
+fn test_normalised_transformed_rational_guess_segments() {
+  for x in [-0.01, -1.0, -13.6, -89.2] {
+    let mut previous = RationalGuessSegment::Lowest;
+    for i in 0..100 {
+      let s = (2.0 * -x).sqrt() * 1.1_f64.powi(i - 50);
+      let beta = normalised_black_call(x, s);
+      let Some(guess) = normalised_transformed_rational_guess(beta, x, 1.0) else {
+        continue;
+      };
+      let bounds = match guess.segment {
+        RationalGuessSegment::Lowest => (0.0, guess.b_l),
+        RationalGuessSegment::LowerMiddle => (guess.b_l, guess.b_c),
+        RationalGuessSegment::UpperMiddle => (guess.b_c, guess.b_h),
+        RationalGuessSegment::Highest => (guess.b_h, (0.5 * x).exp()),
+      };
+      assert!(bounds.0 <= beta && beta <= bounds.1, "x: {}, s: {}, {:?}", x, s, guess);
+      assert!(guess.b_l < guess.b_c && guess.b_c < guess.b_h && guess.s_l < guess.s_c && guess.s_c < guess.s_h);
+      assert!(guess.segment as usize >= previous as usize);
+      previous = guess.segment;
+      assert!(guess.s_left <= guess.s && guess.s <= guess.s_right);
+      assert!(guess.s_left <= s && s <= guess.s_right, "x: {}, s: {}, {:?}", x, s, guess);
+      // the guess is the implied volatility before any iteration
+      assert_eq!(
+        guess.s,
+        normalised_implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(beta, x, 1.0, 0)
+      );
+    }
+    assert_eq!(RationalGuessSegment::Highest, previous);
+  }
+}
+
+#[test]
+fn test_normalised_transformed_rational_guess_maps_to_out_of_the_money_calls() {
+  let (x, s) = (-0.5, 0.4);
+  let call = normalised_transformed_rational_guess(normalised_black(x, s, 1.0), x, 1.0).unwrap();
+  assert_eq!(x, call.x);
+  assert_eq!(Some(call), normalised_transformed_rational_guess(normalised_black(-x, s, -1.0), -x, -1.0));
+  let itm_put = normalised_transformed_rational_guess(normalised_black(x, s, -1.0), x, -1.0).unwrap();
+  assert_eq!(x, itm_put.x);
+  assert!((itm_put.beta - call.beta).abs() <= 1e-15);
+}
+
+#[test]
+fn test_normalised_transformed_rational_guess_without_iteration() {
+  let x = -0.5;
+  assert_eq!(None, normalised_transformed_rational_guess(f64::NAN, x, 1.0));
+  assert_eq!(None, normalised_transformed_rational_guess(0.0, x, 1.0));
+  assert_eq!(None, normalised_transformed_rational_guess((0.5 * x).exp(), x, 1.0));
+  // below the intrinsic value of the in-the-money put
+  assert_eq!(None, normalised_transformed_rational_guess(0.4, x, -1.0));
+}
@@ -0,0 +1,227 @@
+//!
+//! Accuracy of the normalised Black function and of the implied volatility against a multi-precision reference.
+//!
+//! Errors are reported in ulps, i.e. relative errors in units of  ε,  and in ulps per unit of the condition
+//! number of the problem, since no algorithm in double precision can do better than the latter.
+//! Run with `cargo test --release --test reference -- --nocapture` to see the report.
+//!
+
+#![cfg(not(target_arch = "wasm32"))]
+
+#[path = "../benches/common/mod.rs"]
+mod common;
+
+use common::Generator;
+use dashu_float::round::mode::HalfEven;
+use dashu_float::FBig;
+use impl_vol::*;
+
+type Big = FBig<HalfEven>;
+
+/// Number of bits the reference values of two successive precisions must agree to.
+const AGREEMENT_BITS: isize = 80;
+
+/// Precision in bits the reference starts with.
+const INITIAL_PRECISION: usize = 128;
+
+/// Precision in bits beyond which the reference gives up.
+const MAXIMUM_PRECISION: usize = 1 << 14;
+
+/// Maximum number of Newton iterations of the reference implied volatility, started next to the root.
+const NEWTON_ITERATIONS: usize = 16;
+
+/// Number of sampled  (x, s)  points.
+const SAMPLES: usize = 200;
+
+/// Largest factor  b / (s·∂b/∂s)  of the round trip, beyond which the price in double precision determines less
+/// than half the digits of the implied volatility.
+const MAXIMUM_AMPLIFICATION: f64 = 1e8;
+
+/// Maximum error in ulps per unit of the condition number.
+const TOLERANCE: f64 = 8.0;
+
+fn big(x: f64, precision: usize) -> Big {
+  Big::try_from(x).unwrap().with_precision(precision).value()
+}
+
+fn abs(x: Big) -> Big {
+  if x < Big::ZERO {
+    -x
+  } else {
+    x
+  }
+}
+
+fn to_f64(x: &Big) -> f64 {
+  x.to_f64().value()
+}
+
+/// erf(u) = 2/√π · exp(-u²) · Σ 2ⁿ·u²ⁿ⁺¹ / (2n+1)!!, a series of terms with equal signs.
+fn erf(u: &Big, precision: usize) -> Big {
+  let two_u2 = big(2.0, precision) * u * u;
+  let mut term = u.clone();
+  let mut sum = u.clone();
+  let mut n = 0_u32;
+  loop {
+    n += 1;
+    term = term * &two_u2 / big((2 * n + 1) as f64, precision);
+    let next = &sum + &term;
+    if next == sum {
+      break;
+    }
+    sum = next;
+  }
+  big(2.0, precision) / Big::pi(precision).sqrt() * (-(u * u)).exp() * sum
+}
+
+/// Φ(z) = ½·(1 + erf(z/√2))
+fn norm_cdf(z: &Big, precision: usize) -> Big {
+  big(0.5, precision) * (big(1.0, precision) + erf(&(z / big(2.0, precision).sqrt()), precision))
+}
+
+/// b(x, s) = Φ(x/s + s/2)·exp(x/2) - Φ(x/s - s/2)·exp(-x/2)
+fn normalised_black_call_with_precision(x: &Big, s: &Big, precision: usize) -> Big {
+  let (h, t, half_x) = (x / s, s * big(0.5, precision), x * big(0.5, precision));
+  norm_cdf(&(&h + &t), precision) * half_x.exp() - norm_cdf(&(&h - &t), precision) * (-half_x).exp()
+}
+
+/// ∂b/∂x = ½·(Φ(x/s + s/2)·exp(x/2) + Φ(x/s - s/2)·exp(-x/2))
+fn normalised_delta_with_precision(x: &Big, s: &Big, precision: usize) -> Big {
+  let (h, t, half_x) = (x / s, s * big(0.5, precision), x * big(0.5, precision));
+  big(0.5, precision) * (norm_cdf(&(&h + &t), precision) * half_x.exp() + norm_cdf(&(&h - &t), precision) * (-half_x).exp())
+}
+
+/// ∂b/∂s = exp(-(h² + t²)/2) / √(2π)
+fn normalised_vega_with_precision(x: &Big, s: &Big, precision: usize) -> Big {
+  let (h, t) = (x / s, s * big(0.5, precision));
+  (-(big(0.5, precision) * (&h * &h + &t * &t))).exp() / (big(2.0, precision) * Big::pi(precision)).sqrt()
+}
+
+/// Doubles the precision, starting at `precision`, until two successive values of `f` agree to `AGREEMENT_BITS`,
+/// covering the cancellations in  Φ  and  b(x, s).  Returns the value and the precision it needed.
+fn with_sufficient_precision(mut precision: usize, f: impl Fn(usize) -> Big) -> (Big, usize) {
+  let mut previous = f(precision);
+  loop {
+    precision *= 2;
+    assert!(precision <= MAXIMUM_PRECISION, "the reference did not converge");
+    let next = f(precision);
+    let difference = abs(&next - &previous);
+    if difference == Big::ZERO || difference * big(2.0, precision).powi(AGREEMENT_BITS.into()) <= abs(next.clone()) {
+      return (next, precision);
+    }
+    previous = next;
+  }
+}
+
+/// Reference normalised Black call price and the precision it needed.
+fn reference_normalised_black_call(x: f64, s: f64) -> (Big, usize) {
+  with_sufficient_precision(INITIAL_PRECISION, |precision| {
+    normalised_black_call_with_precision(&big(x, precision), &big(s, precision), precision)
+  })
+}
+
+/// Reference implied volatility of the normalised price `beta`, by Newton iterations started at the root `s` of
+/// a nearby price, at no less than the `precision` that price needed. Newton's method cannot converge to more
+/// digits than the price is accurate to, so the iterations are capped and the comparison of precisions decides.
+fn reference_normalised_implied_volatility(beta: f64, x: f64, s: f64, precision: usize) -> Big {
+  with_sufficient_precision(precision, |precision| {
+    let (beta, x) = (big(beta, precision), big(x, precision));
+    let mut s = big(s, precision);
+    for _ in 0..NEWTON_ITERATIONS {
+      let ds = (normalised_black_call_with_precision(&x, &s, precision) - &beta) / normalised_vega_with_precision(&x, &s, precision);
+      s = &s - &ds;
+      if abs(ds) * big(2.0, precision).powi(AGREEMENT_BITS.into()) <= s {
+        break;
+      }
+    }
+    s
+  })
+  .0
+}
+
+/// Condition number  κ = (|x·∂b/∂x| + |s·∂b/∂s|) / b  of the normalised Black call, and the factor  b / (s·∂b/∂s)
+/// that turns a relative error of the price into a relative error of the implied volatility.
+fn condition_numbers(x: f64, s: f64, b: &Big, precision: usize) -> (f64, f64) {
+  let (delta, _) = with_sufficient_precision(precision, |precision| normalised_delta_with_precision(&big(x, precision), &big(s, precision), precision));
+  let vega = normalised_vega_with_precision(&big(x, precision), &big(s, precision), precision);
+  let b_over_vega = to_f64(&(b / vega.clone()));
+  ((x * to_f64(&(delta / b.clone()))).abs() + s / b_over_vega, b_over_vega / s)
+}
+
+/// Relative error in units of  ε.
+fn ulps(expected: &Big, actual: f64) -> f64 {
+  to_f64(&abs((big(actual, expected.precision()) - expected) / expected)) / f64::EPSILON
+}
+
+/// Maximum error over the samples, in ulps and in ulps per unit of the condition number.
+#[derive(Default)]
+struct Report {
+  maximum: (f64, f64, f64),
+  maximum_relative_to_condition: (f64, f64, f64),
+}
+
+impl Report {
+  fn add(&mut self, error: f64, condition: f64, x: f64, s: f64) {
+    if error > self.maximum.0 {
+      self.maximum = (error, x, s);
+    }
+    if error / condition > self.maximum_relative_to_condition.0 {
+      self.maximum_relative_to_condition = (error / condition, x, s);
+    }
+  }
+
+  fn print(&self, name: &str) {
+    let ((e, x, s), (c, cx, cs)) = (self.maximum, self.maximum_relative_to_condition);
+    println!("{}: maximum relative error {:.1} ulps at x = {:e}, s = {:e}", name, e, x, s);
+    println!("{}: maximum relative error {:.2} ulps per unit of condition at x = {:e}, s = {:e}", name, c, cx, cs);
+  }
+}
+
+/// Samples  x = ±exp(u)  and  s = exp(v)  log-uniformly, excluding prices below the normal range of f64.
+fn samples() -> Vec<(f64, f64)> {
+  let mut generator = Generator::default();
+  let mut samples = Vec::with_capacity(SAMPLES);
+  while samples.len() < SAMPLES {
+    let x = generator.uniform(-1.0, 1.0).signum() * generator.uniform(-12.0, 4.0).exp();
+    let s = generator.uniform(-8.0, 3.0).exp();
+    if (x / s) * (x / s) + (s / 2.0) * (s / 2.0) < 1400.0 {
+      samples.push((x, s));
+    }
+  }
+  samples
+}
+
+#[test]
+fn test_normalised_black_call_against_reference() {
+  let mut report = Report::default();
+  for (x, s) in samples() {
+    let (b, precision) = reference_normalised_black_call(x, s);
+    let (condition, _) = condition_numbers(x, s, &b, precision);
+    report.add(ulps(&b, normalised_black_call(x, s)), 1.0 + condition, x, s);
+  }
+  report.print("normalised_black_call");
+  assert!(report.maximum_relative_to_condition.0 < TOLERANCE);
+}
+
+#[test]
+fn test_implied_volatility_round_trip_against_reference() {
+  let mut report = Report::default();
+  let mut skipped = 0;
+  // b(-|x|, s)  is the price of the out-of-the-money option, the price rounded to f64 of an in-the-money option
+  // may well be below the intrinsic value
+  for (x, s) in samples().into_iter().map(|(x, s)| (-x.abs(), s)) {
+    let (b, precision) = reference_normalised_black_call(x, s);
+    let (condition, amplification) = condition_numbers(x, s, &b, precision);
+    if amplification > MAXIMUM_AMPLIFICATION {
+      skipped += 1;
+      continue;
+    }
+    let beta = to_f64(&b);
+    let expected = reference_normalised_implied_volatility(beta, x, s, precision);
+    let actual = normalised_implied_volatility_from_a_transformed_rational_guess(beta, x, 1.0);
+    report.add(ulps(&expected, actual), (1.0 + condition) * (1.0 + amplification), x, s);
+  }
+  report.print("implied volatility round trip");
+  println!("implied volatility round trip: {} of {} samples skipped as too ill-conditioned", skipped, SAMPLES);
+  assert!(report.maximum_relative_to_condition.0 < TOLERANCE);
+}
@@ -0,0 +1,88 @@
+//!
+//! Property-based round trips  s → b(x, s) → s  over the four regions of the rational guess.
+//!
+
+#![cfg(not(target_arch = "wasm32"))]
+
+use impl_vol::*;
+use proptest::prelude::*;
+
+/// Maximum relative error of the implied volatility in units of  ε,  per unit of the amplification of rounding errors.
+const TOLERANCE: f64 = 16.0;
+
+/// Boundaries  s_l < s_c < s_u  of the regions of the rational guess for  x < 0:  b(x, s_l), b(x, s_c)  and  b(x, s_u)
+/// separate the lower, lower middle, upper middle and upper regions of the normalised price.
+fn region_boundaries(x: f64) -> (f64, f64, f64) {
+  let s_c = (2.0 * x.abs()).sqrt();
+  let b_c = normalised_black_call(x, s_c);
+  let v_c = normalised_vega(x, s_c);
+  (s_c - b_c / v_c, s_c, s_c + ((0.5 * x).exp() - b_c) / v_c)
+}
+
+/// Total volatility at the fraction `lambda` of the given region, interpolated geometrically.
+/// Prices in the lower region are limited to the normal range of f64 by  |x|/s < 30.
+fn total_volatility(x: f64, region: usize, lambda: f64) -> Option<f64> {
+  let (s_l, s_c, s_u) = region_boundaries(x);
+  let (a, b) = [(x.abs() / 30.0, s_l), (s_l, s_c), (s_c, s_u), (s_u, 2.0 * s_u)][region];
+  (0.0 < a && a < b).then(|| a * (b / a).powf(lambda))
+}
+
+/// Factor by which relative rounding errors of the price, and for in-the-money options of the intrinsic value that is
+/// subtracted from it, are amplified into a relative error of the implied volatility:  (b + |intrinsic terms|) / (s·∂b/∂s).
+fn amplification(beta: f64, x: f64, s: f64, q: f64) -> f64 {
+  let intrinsic = if q * x > 0.0 { (0.5 * x).exp() + (-0.5 * x).exp() } else { 0.0 };
+  (beta + intrinsic) / (s * normalised_vega(x, s))
+}
+
+/// Checks that the implied volatility of the price of a call (q=1) or put (q=-1) recovers `s`.
+fn check_round_trip(x: f64, s: f64, q: f64) -> Result<(), TestCaseError> {
+  let beta = normalised_black(x, s, q);
+  // the price attains its maximum  exp(q·x/2)  in double precision for large  s,  up to the rounding of the latter, as the math
+  // functions of the libm build may round differently from those of this test
+  prop_assume!(beta < (0.5 * q * x).exp() * (1.0 - 4.0 * f64::EPSILON));
+  // and so does the time value of options in the money, which is what remains after subtracting the intrinsic value
+  if q * x > 0.0 {
+    let b_max = (0.5 * x).exp();
+    prop_assume!(beta - q * (b_max - 1.0 / b_max) < (-0.5 * q * x).exp() - 4.0 * f64::EPSILON * beta);
+  }
+  let implied = normalised_implied_volatility_from_a_transformed_rational_guess(beta, x, q);
+  let error = ((implied - s) / s).abs() / f64::EPSILON;
+  prop_assert!(
+    error <= TOLERANCE * (1.0 + amplification(beta, x, s, q)),
+    "x: {:e}, s: {:e}, q: {}, beta: {:e}, implied: {:e}, error: {} ulps",
+    x,
+    s,
+    q,
+    beta,
+    implied,
+    error
+  );
+  Ok(())
+}
+
+proptest! {
+  #![proptest_config(ProptestConfig::with_cases(2000))]
+
+  #[test]
+  fn test_out_of_the_money_round_trip(u in -12.0..4.0_f64, region in 0..4_usize, lambda in 0.0..1.0_f64) {
+    let x = -u.exp();
+    let s = total_volatility(x, region, lambda);
+    prop_assume!(s.is_some());
+    let s = s.unwrap();
+    // the call at x and the put at -x have the same price
+    check_round_trip(x, s, 1.0)?;
+    check_round_trip(-x, s, -1.0)?;
+  }
+
+  #[test]
+  fn test_in_the_money_round_trip(u in -12.0..4.0_f64, region in 0..4_usize, lambda in 0.0..1.0_f64) {
+    let x = -u.exp();
+    let s = total_volatility(x, region, lambda);
+    prop_assume!(s.is_some());
+    let s = s.unwrap();
+    // at least half the digits of the time value have to survive its addition to the intrinsic value
+    prop_assume!(normalised_black_call(x, s) > f64::EPSILON.sqrt() * normalised_black_call(-x, s));
+    check_round_trip(-x, s, 1.0)?;
+    check_round_trip(x, s, -1.0)?;
+  }
+}
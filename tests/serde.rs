@@ -0,0 +1,55 @@
+#![cfg(all(feature = "serde", not(target_arch = "wasm32")))]
+
+use impl_vol::*;
+
+#[test]
+fn test_option_quote_json() {
+  let quote = OptionQuote {
+    kind: OptionKind::Put,
+    forward: Forward::new(2170.4221251767294).unwrap(),
+    strike: Strike::new(2300.0).unwrap(),
+    expiry: Expiry::new(0.926027).unwrap(),
+    price: 244.3719826834357,
+  };
+  let json = serde_json::to_string(&quote).unwrap();
+  assert_eq!(
+    r#"{"kind":"Put","forward":2170.4221251767294,"strike":2300.0,"expiry":0.926027,"price":244.3719826834357}"#,
+    json
+  );
+  assert_eq!(quote, serde_json::from_str::<OptionQuote>(&json).unwrap());
+}
+
+#[test]
+fn test_validated_inputs_json() {
+  assert_eq!(Volatility::new(0.25), serde_json::from_str("0.25").ok());
+  assert!(serde_json::from_str::<Forward>("0.0").is_err());
+  assert!(serde_json::from_str::<Strike>("-1.0").is_err());
+  assert!(serde_json::from_str::<Expiry>("0.0").is_err());
+  assert!(serde_json::from_str::<Volatility>("-0.1").is_err());
+  let json = r#"{"kind":"Call","forward":100.0,"strike":-90.0,"expiry":0.5,"price":12.0}"#;
+  assert!(serde_json::from_str::<OptionQuote>(json).is_err());
+}
+
+/// Persisted results replay bit for bit, the maximum iterations are left at their default for concurrently running tests.
+#[test]
+fn test_solve_result_json_replay() {
+  let (f, t) = (2170.4221251767294, 0.926027);
+  for (k, price, kind) in [
+    (1700.0, 539.1269453050334, OptionKind::Call),
+    (2300.0, 244.3719826834357, OptionKind::Put),
+    (1000.0, 1.0, OptionKind::Call),
+  ] {
+    let quote = OptionQuote {
+      kind,
+      forward: Forward::new(f).unwrap(),
+      strike: Strike::new(k).unwrap(),
+      expiry: Expiry::new(t).unwrap(),
+      price,
+    };
+    let result = quote.solve();
+    let persisted: SolveResult = serde_json::from_str(&serde_json::to_string(&result).unwrap()).unwrap();
+    assert_eq!(result, persisted);
+    assert_eq!(result.volatility.to_bits(), persisted.volatility.to_bits());
+    assert_eq!(result.volatility.to_bits(), persisted.replay().to_bits());
+  }
+}
@@ -0,0 +1,60 @@
+use impl_vol::*;
+
+fn quote(kind: OptionKind, f: f64, k: f64, t: f64, price: f64) -> OptionQuote {
+  OptionQuote {
+    kind,
+    forward: Forward::new(f).unwrap(),
+    strike: Strike::new(k).unwrap(),
+    expiry: Expiry::new(t).unwrap(),
+    price,
+  }
+}
+
+#[test]
+fn test_solve_and_replay() {
+  assert_eq!(SolverConfig::default(), SolverConfig::current());
+  let (f, k, t) = (2170.4221251767294, 1900.0, 0.926027);
+  let call = quote(OptionKind::Call, f, k, t, 383.9915991044646);
+  let result = call.solve();
+  assert_eq!(SolverConfig::default(), result.config);
+  assert_eq!(
+    implied_volatility_from_a_transformed_rational_guess(call.price, f, k, t, 1.0).to_bits(),
+    result.volatility.to_bits()
+  );
+  assert!(result.iterations > 0 && result.iterations <= result.config.maximum_iterations);
+  assert!(!result.is_below_intrinsic() && !result.is_above_maximum());
+
+  // A single Newton iteration differs from the default result, and leaves the settings in effect unchanged.
+  let config = SolverConfig {
+    maximum_iterations: 1,
+    householder_method_order: 2,
+  };
+  let newton = call.solve_with(config);
+  assert_eq!(config, newton.config);
+  assert_eq!(1, newton.iterations);
+  assert_ne!(result.volatility, newton.volatility);
+  // The recorded order is used, not the one in effect.
+  assert_ne!(
+    implied_volatility_from_a_transformed_rational_guess_with_limited_iterations(call.price, f, k, t, 1.0, 1).to_bits(),
+    newton.volatility.to_bits()
+  );
+  assert_eq!(SolverConfig::default(), SolverConfig::current());
+  // Replaying uses the recorded settings, also concurrently with other settings.
+  std::thread::scope(|scope| {
+    for _ in 0..4 {
+      scope.spawn(|| {
+        for _ in 0..1000 {
+          assert_eq!(result.volatility.to_bits(), result.replay().to_bits());
+          assert_eq!(newton.volatility.to_bits(), newton.replay().to_bits());
+        }
+      });
+    }
+  });
+  assert_eq!(SolverConfig::default(), SolverConfig::current());
+
+  let below = quote(OptionKind::Call, f, k, t, 200.0).solve();
+  assert!(below.is_below_intrinsic() && !below.is_above_maximum());
+  assert_eq!(0, below.iterations);
+  let above = quote(OptionKind::Put, f, k, t, k).solve();
+  assert!(above.is_above_maximum() && !above.is_below_intrinsic());
+}
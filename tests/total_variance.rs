@@ -0,0 +1,69 @@
+use impl_vol::*;
+
+fn close(expected: f64, actual: f64, tolerance: f64) {
+  assert!((expected - actual).abs() <= tolerance * expected.abs(), "expected: {}\n  actual: {},", expected, actual);
+}
+
+#[test]
+fn test_total_variance_round_trip() {
+  let (f, t) = (2170.4221251767294, 0.926027);
+  for strike in [1700.0, 2100.0, 2170.4221251767294, 2300.0, 2700.0] {
+    let k = (strike / f).ln();
+    for q in [1.0, -1.0] {
+      let sigma = 0.25;
+      let w = sigma * sigma * t;
+      let price = black_from_total_variance(f, k, w, q);
+      close(black(f, strike, sigma, t, q), price, 1e-14);
+      close(w, implied_total_variance(price, f, k, q), 1e-13);
+      let implied_volatility = implied_volatility_from_a_transformed_rational_guess(price, f, strike, t, q);
+      close(implied_volatility * implied_volatility * t, implied_total_variance(price, f, k, q), 1e-14);
+    }
+  }
+}
+
+#[test]
+fn test_total_variance_forward_normalised_round_trip() {
+  let (f, w) = (2170.4221251767294, 0.04);
+  for strike in [1700.0, 2170.4221251767294, 2700.0] {
+    let k = (strike / f).ln();
+    for q in [1.0, -1.0] {
+      let c = forward_normalised_black_from_total_variance(k, w, q);
+      close(black_from_total_variance(f, k, w, q) / f, c, 1e-15);
+      close(w, implied_total_variance_from_forward_normalised_price(c, k, q), 1e-13);
+    }
+  }
+}
+
+#[test]
+fn test_total_variance_zero_expiry() {
+  let f = 100.0;
+  let k = (90.0_f64 / f).ln();
+  let intrinsic = black_from_total_variance(f, k, 0.0, 1.0);
+  close(10.0, intrinsic, 1e-15);
+  assert_eq!(0.0, black_from_total_variance(f, k, 0.0, -1.0));
+  assert_eq!(0.0, implied_total_variance(intrinsic, f, k, 1.0));
+  assert_eq!(0.0, implied_total_variance(0.0, f, k, -1.0));
+  assert_eq!(0.0, implied_total_variance_from_forward_normalised_price(0.0, k, -1.0));
+  assert_eq!(VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_BELOW_INTRINSIC, implied_total_variance(9.5, f, k, 1.0));
+  assert_eq!(VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM, implied_total_variance(f, f, k, 1.0));
+  assert!(implied_total_variance(f64::NAN, f, k, 1.0).is_nan());
+}
+
+#[test]
+fn test_total_variance_near_zero_expiry() {
+  // one minute and one millisecond at 20% volatility, at and out of the money where the price is all time value
+  let (f, sigma) = (100.0, 0.2);
+  for t in [1.0 / (365.0 * 24.0 * 60.0), 1.0 / (365.0 * 24.0 * 3600.0 * 1000.0)] {
+    let w = sigma * sigma * t;
+    for (k, q) in [(0.0, 1.0), (0.0, -1.0), (0.5 * w.sqrt(), 1.0), (-0.5 * w.sqrt(), -1.0)] {
+      let price = black_from_total_variance(f, k, w, q);
+      assert!(price > 0.0);
+      close(w, implied_total_variance(price, f, k, q), 1e-13);
+      close(
+        w,
+        implied_total_variance_from_forward_normalised_price(forward_normalised_black_from_total_variance(k, w, q), k, q),
+        1e-13,
+      );
+    }
+  }
+}
@@ -0,0 +1,107 @@
+use impl_vol::*;
+
+fn close(expected: f64, actual: f64, tolerance: f64) {
+  assert!((expected - actual).abs() <= tolerance * expected, "expected: {}\n  actual: {},", expected, actual);
+}
+
+fn identical(cold: f64, warm: f64, prior: f64) {
+  assert_eq!(cold.to_bits(), warm.to_bits(), "prior: {}, cold: {}, warm: {}", prior, cold, warm);
+}
+
+#[test]
+fn test_implied_volatility_with_prior_at_the_rational_guess_is_the_cold_solve() {
+  let mut segments = vec![];
+  for i in 0..=48 {
+    let x = -12.0 + 0.5 * i as f64;
+    for j in 0..=40 {
+      let s = 0.01 * 1.15_f64.powi(j);
+      for q in [1.0, -1.0] {
+        let beta = normalised_black(x, s, q);
+        let Some(guess) = normalised_transformed_rational_guess(beta, x, q) else {
+          continue;
+        };
+        if !segments.contains(&guess.segment) {
+          segments.push(guess.segment);
+        }
+        identical(
+          normalised_implied_volatility_from_a_transformed_rational_guess(beta, x, q),
+          normalised_implied_volatility_from_a_transformed_rational_guess_with_prior(beta, x, q, guess.s),
+          guess.s,
+        );
+      }
+    }
+  }
+  assert_eq!(4, segments.len());
+}
+
+#[test]
+fn test_implied_volatility_with_prior_converges() {
+  let (f, t) = (2170.4221251767294, 0.926027);
+  for k in [1000.0, 1700.0, 2170.0, 2700.0, 5000.0] {
+    for sigma in [0.05, 0.25, 1.5] {
+      let q = if k < f { -1.0 } else { 1.0 };
+      let price = black(f, k, sigma, t, q);
+      close(sigma, implied_volatility_from_a_transformed_rational_guess(price, f, k, t, q), 1e-13);
+      for bump in [-5e-4, -1e-4, 0.0, 2e-4] {
+        close(
+          sigma,
+          implied_volatility_from_a_transformed_rational_guess_with_prior(price, f, k, t, q, sigma + bump),
+          1e-13,
+        );
+      }
+    }
+  }
+}
+
+#[test]
+fn test_implied_volatility_with_prior_in_the_lowest_segment() {
+  // far out of the money,  |x|/s ≳ 30,  where the price is tiny and the objective function is  1/ln(b) - 1/ln(beta)
+  for (x, s) in [(-6.0, 0.165), (-6.0, 0.2), (-12.0, 0.35), (-3.0, 0.09), (-1.0, 0.03), (-0.1, 0.0033)] {
+    for (x, q) in [(x, 1.0), (-x, -1.0)] {
+      let beta = normalised_black(x, s, q);
+      assert!(beta > 0.0);
+      assert_eq!(
+        Some(RationalGuessSegment::Lowest),
+        normalised_transformed_rational_guess(beta, x, q).map(|guess| guess.segment)
+      );
+      close(s, normalised_implied_volatility_from_a_transformed_rational_guess(beta, x, q), 1e-13);
+      for bump in [0.9, 0.95, 0.99, 0.999, 1.001, 1.01, 1.05, 1.1] {
+        close(s, normalised_implied_volatility_from_a_transformed_rational_guess_with_prior(beta, x, q, s * bump), 1e-13);
+      }
+    }
+  }
+  // a prior 5bp below a volatility of 2% far out of the money
+  let (f, k, t, sigma) = (2170.42, 4450.0, 0.926027, 0.02);
+  let price = black(f, k, sigma, t, 1.0);
+  close(sigma, implied_volatility_from_a_transformed_rational_guess(price, f, k, t, 1.0), 1e-13);
+  close(
+    sigma,
+    implied_volatility_from_a_transformed_rational_guess_with_prior(price, f, k, t, 1.0, sigma - 5e-4),
+    1e-13,
+  );
+}
+
+#[test]
+fn test_implied_volatility_with_invalid_prior() {
+  let (f, k, t, q) = (100.0, 130.0, 0.5, 1.0);
+  let price = black(f, k, 0.3, t, q);
+  let cold = implied_volatility_from_a_transformed_rational_guess(price, f, k, t, q);
+  // priors outside the bracket of the segment fall back to the rational guess
+  for prior in [f64::NAN, -0.3, 0.0, 1e6, f64::INFINITY] {
+    identical(cold, implied_volatility_from_a_transformed_rational_guess_with_prior(price, f, k, t, q, prior), prior);
+  }
+  // deep out of the money, a prior whose price is denormalised would be of no use
+  let (k, sigma) = (f * 0.13_f64.exp(), 0.005);
+  let price = black(f, k, sigma, t, q);
+  assert!(price > 0.0 && price < 1e-290);
+  let cold = implied_volatility_from_a_transformed_rational_guess(price, f, k, t, q);
+  close(sigma, cold, 1e-12);
+  let prior = sigma - 3e-4;
+  assert!(black(f, k, prior, t, q) < f64::MIN_POSITIVE * f);
+  identical(cold, implied_volatility_from_a_transformed_rational_guess_with_prior(price, f, k, t, q, prior), prior);
+  assert!(implied_volatility_from_a_transformed_rational_guess_with_prior(f64::NAN, f, k, t, q, sigma).is_nan());
+  assert_eq!(
+    VOLATILITY_VALUE_TO_SIGNAL_PRICE_IS_ABOVE_MAXIMUM,
+    implied_volatility_from_a_transformed_rational_guess_with_prior(f, f, k, t, q, sigma)
+  );
+}
@@ -0,0 +1,26 @@
+#![cfg(all(target_arch = "wasm32", feature = "wasm"))]
+
+use impl_vol::wasm::*;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn test_scalar_functions() {
+  assert_eq!(0.3148253556850184, wasm_implied_volatility(539.1269453050334, 2170.4221251767294, 1700.00, 0.926027, 1.0));
+  assert_eq!(impl_vol::black(100.0, 110.0, 0.2, 1.0, -1.0), wasm_black(100.0, 110.0, 0.2, 1.0, -1.0));
+  let beta = wasm_normalised_black(-0.1, 0.25, 1.0);
+  assert!((wasm_normalised_implied_volatility(beta, -0.1, 1.0) - 0.25).abs() < 1e-15);
+  assert_eq!(
+    wasm_volatility_value_to_signal_price_is_below_intrinsic(),
+    wasm_implied_volatility(1.0, 100.0, 50.0, 1.0, 1.0)
+  );
+}
+
+#[wasm_bindgen_test]
+fn test_batch_functions() {
+  let volatility = wasm_implied_volatility_batch(&[539.1269453050334, 459.18797785046036], &[2170.4221251767294], &[1700.00, 1800.00], &[0.926027], &[1.0]).unwrap();
+  assert_eq!(vec![0.3148253556850184, 0.3005835339311901], volatility);
+  let price = wasm_black_batch(&[100.0], &[90.0, 100.0, 110.0], &[0.2], &[1.0], &[1.0, 1.0, -1.0]).unwrap();
+  assert_eq!(3, price.len());
+  assert_eq!(impl_vol::black(100.0, 110.0, 0.2, 1.0, -1.0), price[2]);
+  assert!(wasm_black_batch(&[100.0, 101.0], &[90.0, 100.0, 110.0], &[0.2], &[1.0], &[1.0]).is_err());
+}